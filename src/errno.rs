@@ -13,7 +13,7 @@
 
 use crate::Result;
 use cfg_if::cfg_if;
-use libc::{c_int, c_void};
+use libc::{c_int, c_long, c_void};
 use std::{error, fmt, io};
 
 pub use self::consts::*;
@@ -137,6 +137,38 @@ impl Errno {
     }
 }
 
+/// Call a function that returns `-1` both on error and for some valid
+/// results, like [`sysconf`](crate::unistd::sysconf).
+///
+/// errno is cleared before calling `f`, so that a `-1` result with errno
+/// still unset can be told apart from an error.  Returns `Ok(None)` in that
+/// case, `Ok(Some(x))` for any other result, and the error otherwise.
+///
+/// # Example
+/// ```
+/// use nix::errno::errno_clearing_call;
+///
+/// let page_size =
+///     errno_clearing_call(|| unsafe { libc::sysconf(libc::_SC_PAGESIZE) });
+/// assert!(page_size.unwrap().unwrap() > 0);
+/// ```
+pub fn errno_clearing_call<F>(f: F) -> Result<Option<c_long>>
+where
+    F: FnOnce() -> c_long,
+{
+    Errno::clear();
+    let raw = f();
+    if raw == -1 {
+        if Errno::last_raw() == 0 {
+            Ok(None)
+        } else {
+            Err(Errno::last())
+        }
+    } else {
+        Ok(Some(raw))
+    }
+}
+
 /// The sentinel value indicates that a function failed and more detailed
 /// information about the error can be found in `errno`
 pub trait ErrnoSentinel: Sized {
@@ -290,6 +290,37 @@ pub fn openat<P: ?Sized + NixPath, Fd: std::os::fd::AsFd>(
     Ok( unsafe { OwnedFd::from_raw_fd(fd)  } )
 }
 
+/// Create an unnamed temporary regular file in the directory `dir`.
+///
+/// This is [`open`] with `O_TMPFILE` added to `oflag`, which must also contain
+/// `O_WRONLY` or `O_RDWR`.  The file is deleted once its last file descriptor
+/// is closed, unless it was given a name first.  Unless `O_EXCL` is given, it
+/// can be linked into the file system using `/proc/self/fd`:
+///
+/// ```no_run
+/// # use nix::fcntl::{open_tmpfile, OFlag, AtFlags, AT_FDCWD};
+/// # use nix::sys::stat::Mode;
+/// # use nix::unistd::linkat;
+/// # use std::os::fd::AsRawFd;
+/// let fd = open_tmpfile("/tmp", OFlag::O_RDWR, Mode::S_IRUSR | Mode::S_IWUSR)
+///     .unwrap();
+/// // ... write the file's contents ...
+/// let proc_path = format!("/proc/self/fd/{}", fd.as_raw_fd());
+/// linkat(AT_FDCWD, proc_path.as_str(), AT_FDCWD, "/tmp/done",
+///     AtFlags::AT_SYMLINK_FOLLOW).unwrap();
+/// ```
+///
+/// # See Also
+/// [`open(2)`](https://man7.org/linux/man-pages/man2/open.2.html)
+#[cfg(linux_android)]
+pub fn open_tmpfile<P: ?Sized + NixPath>(
+    dir: &P,
+    oflag: OFlag,
+    mode: Mode,
+) -> Result<OwnedFd> {
+    open(dir, oflag | OFlag::O_TMPFILE, mode)
+}
+
 cfg_if::cfg_if! {
     if #[cfg(target_os = "linux")] {
         libc_bitflags! {
@@ -382,6 +413,10 @@ cfg_if::cfg_if! {
         /// `openat2` is an extension of the [`openat`] function that allows the caller
         /// to control how path resolution happens.
         ///
+        /// Flags unknown to the running kernel are rejected with `EINVAL`
+        /// rather than ignored, and kernels without `openat2` fail with
+        /// `ENOSYS`.
+        ///
         /// # See also
         ///
         /// [openat2](https://man7.org/linux/man-pages/man2/openat2.2.html)
@@ -468,6 +503,9 @@ feature! {
 ///
 /// A `renameat2` call with an empty flags argument is equivalent to `renameat`.
 ///
+/// If the underlying filesystem does not support one of the requested `flags`,
+/// `EINVAL` is returned.
+///
 /// # See Also
 /// * [`rename`](https://man7.org/linux/man-pages/man2/rename.2.html)
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
@@ -782,6 +820,10 @@ pub enum FcntlArg<'a> {
     #[cfg(linux_android)]
     F_GETPIPE_SZ,
     /// Change the capacity of a pipe
+    ///
+    /// The kernel may round the size up, and `fcntl` returns the capacity
+    /// actually set.  Unprivileged processes get `EPERM` when asking for more
+    /// than `/proc/sys/fs/pipe-max-size`.
     #[cfg(linux_android)]
     F_SETPIPE_SZ(c_int),
     /// Look up the path of an open file descriptor, if possible.
@@ -977,6 +1019,154 @@ pub fn fcntl<Fd: std::os::fd::AsFd>(fd: Fd, arg: FcntlArg) -> Result<c_int> {
     Errno::result(res)
 }
 
+/// Add seals to a file, restricting the operations that may be performed
+/// on it.
+///
+/// Seals may only be added to files that support sealing, such as a memfd
+/// created with [`MFD_ALLOW_SEALING`](crate::sys::memfd::MFdFlags::MFD_ALLOW_SEALING).
+/// Otherwise, `EPERM` is returned.
+///
+/// # See Also
+/// * [`fcntl`](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+#[cfg(any(linux_android, target_os = "freebsd"))]
+pub fn add_seals<Fd: std::os::fd::AsFd>(
+    fd: Fd,
+    seals: SealFlag,
+) -> Result<()> {
+    fcntl(fd, F_ADD_SEALS(seals)).map(drop)
+}
+
+/// Get the seals currently set on a file.
+///
+/// # See Also
+/// * [`fcntl`](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+#[cfg(any(linux_android, target_os = "freebsd"))]
+pub fn get_seals<Fd: std::os::fd::AsFd>(fd: Fd) -> Result<SealFlag> {
+    fcntl(fd, F_GET_SEALS).map(SealFlag::from_bits_truncate)
+}
+
+// From include/uapi/linux/fcntl.h, where they are F_LINUX_SPECIFIC_BASE (1024)
+// plus 11 to 14.  The RWH_WRITE_LIFE_* values of `RwHint` come from the same
+// header.
+#[cfg(target_os = "linux")]
+const F_GET_RW_HINT: c_int = 1035;
+#[cfg(target_os = "linux")]
+const F_SET_RW_HINT: c_int = 1036;
+#[cfg(target_os = "linux")]
+const F_GET_FILE_RW_HINT: c_int = 1037;
+#[cfg(target_os = "linux")]
+const F_SET_FILE_RW_HINT: c_int = 1038;
+
+/// The expected lifetime of data written to a file, for use with
+/// [`set_rw_hint`].
+///
+/// Storage devices may use it to place data with similar lifetimes together.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(u64)]
+pub enum RwHint {
+    /// No hint has been set.
+    NotSet = 0,
+    /// No specific lifetime is expected.
+    None = 1,
+    /// Data is expected to be overwritten soon.
+    Short = 2,
+    /// Data is expected to live longer than `Short` data.
+    Medium = 3,
+    /// Data is expected to live longer than `Medium` data.
+    Long = 4,
+    /// Data is expected to live the longest.
+    Extreme = 5,
+}
+
+#[cfg(target_os = "linux")]
+impl TryFrom<u64> for RwHint {
+    type Error = Errno;
+
+    fn try_from(hint: u64) -> Result<Self> {
+        match hint {
+            0 => Ok(RwHint::NotSet),
+            1 => Ok(RwHint::None),
+            2 => Ok(RwHint::Short),
+            3 => Ok(RwHint::Medium),
+            4 => Ok(RwHint::Long),
+            5 => Ok(RwHint::Extreme),
+            _ => Err(Errno::EINVAL),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_hint<Fd: std::os::fd::AsFd>(fd: Fd, cmd: c_int) -> Result<RwHint> {
+    use std::os::fd::AsRawFd;
+
+    let mut hint: u64 = 0;
+    let res = unsafe { libc::fcntl(fd.as_fd().as_raw_fd(), cmd, &mut hint) };
+    Errno::result(res)?;
+    RwHint::try_from(hint)
+}
+
+#[cfg(target_os = "linux")]
+fn set_hint<Fd: std::os::fd::AsFd>(
+    fd: Fd,
+    cmd: c_int,
+    hint: RwHint,
+) -> Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let hint = hint as u64;
+    let res = unsafe { libc::fcntl(fd.as_fd().as_raw_fd(), cmd, &hint) };
+    Errno::result(res).map(drop)
+}
+
+/// Get the write lifetime hint of the inode of a file.
+///
+/// # See Also
+/// * [`fcntl`](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+#[cfg(target_os = "linux")]
+pub fn get_rw_hint<Fd: std::os::fd::AsFd>(fd: Fd) -> Result<RwHint> {
+    get_hint(fd, F_GET_RW_HINT)
+}
+
+/// Set the write lifetime hint of the inode of a file.
+///
+/// The hint applies to all writes to the inode, through any file descriptor.
+///
+/// # See Also
+/// * [`fcntl`](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+#[cfg(target_os = "linux")]
+pub fn set_rw_hint<Fd: std::os::fd::AsFd>(fd: Fd, hint: RwHint) -> Result<()> {
+    set_hint(fd, F_SET_RW_HINT, hint)
+}
+
+/// Get the write lifetime hint of an open file description.
+///
+/// Linux 5.17 removed support for per-file hints, so this fails with
+/// `EINVAL` on newer kernels.
+///
+/// # See Also
+/// * [`fcntl`](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+#[cfg(target_os = "linux")]
+pub fn get_file_rw_hint<Fd: std::os::fd::AsFd>(fd: Fd) -> Result<RwHint> {
+    get_hint(fd, F_GET_FILE_RW_HINT)
+}
+
+/// Set the write lifetime hint of an open file description, overriding the
+/// hint of its inode.
+///
+/// Linux 5.17 removed support for per-file hints, so this fails with
+/// `EINVAL` on newer kernels.
+///
+/// # See Also
+/// * [`fcntl`](https://man7.org/linux/man-pages/man2/fcntl.2.html)
+#[cfg(target_os = "linux")]
+pub fn set_file_rw_hint<Fd: std::os::fd::AsFd>(
+    fd: Fd,
+    hint: RwHint,
+) -> Result<()> {
+    set_hint(fd, F_SET_FILE_RW_HINT, hint)
+}
+
 /// Operations for use with [`Flock::lock`].
 #[cfg(not(any(target_os = "redox", target_os = "solaris")))]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -1162,6 +1352,57 @@ unsafe impl Flockable for std::fs::File {}
 // Safety: `OwnedFd` is not [std::clone::Clone].
 #[cfg(not(any(target_os = "redox", target_os = "solaris")))]
 unsafe impl Flockable for OwnedFd {}
+
+/// Acquire or release an open file description lock on a range of a file.
+///
+/// Unlike the locks of [`F_SETLK`](FcntlArg::F_SETLK), open file description
+/// locks belong to the open file description rather than to the process, so
+/// they conflict between file descriptors opened separately by different
+/// threads of the same process.  The range starts at `start` and is `len`
+/// bytes long, or extends to the end of the file if `len` is 0.
+///
+/// The blocking variants of [`FlockArg`] wait for conflicting locks to be
+/// released, the nonblocking ones fail with `EAGAIN` instead.
+///
+/// # Example
+/// ```
+/// # use nix::fcntl::{ofd_lock, FlockArg};
+/// # use tempfile::tempfile;
+/// let f = tempfile().unwrap();
+/// // Lock the first 512 bytes of the file
+/// ofd_lock(&f, FlockArg::LockExclusive, 0, 512).unwrap();
+/// // Do stuff, then release the lock
+/// ofd_lock(&f, FlockArg::Unlock, 0, 512).unwrap();
+/// ```
+#[cfg(linux_android)]
+pub fn ofd_lock<Fd: std::os::fd::AsFd>(
+    fd: Fd,
+    arg: FlockArg,
+    start: libc::off_t,
+    len: libc::off_t,
+) -> Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let (l_type, cmd) = match arg {
+        FlockArg::LockShared => (libc::F_RDLCK, libc::F_OFD_SETLKW),
+        FlockArg::LockExclusive => (libc::F_WRLCK, libc::F_OFD_SETLKW),
+        FlockArg::LockSharedNonblock => (libc::F_RDLCK, libc::F_OFD_SETLK),
+        FlockArg::LockExclusiveNonblock => (libc::F_WRLCK, libc::F_OFD_SETLK),
+        #[allow(deprecated)]
+        FlockArg::Unlock | FlockArg::UnlockNonblock => {
+            (libc::F_UNLCK, libc::F_OFD_SETLK)
+        }
+    };
+    let mut flock: libc::flock = unsafe {
+        std::mem::zeroed() // required for Linux/mips
+    };
+    flock.l_type = l_type as libc::c_short;
+    flock.l_whence = libc::SEEK_SET as libc::c_short;
+    flock.l_start = start;
+    flock.l_len = len;
+    let res = unsafe { libc::fcntl(fd.as_fd().as_raw_fd(), cmd, &flock) };
+    Errno::result(res).map(drop)
+}
 }
 
 #[cfg(linux_android)]
@@ -12,7 +12,7 @@ use std::mem;
 use std::option::Option;
 
 use crate::net::if_::*;
-use crate::sys::socket::{SockaddrLike, SockaddrStorage};
+use crate::sys::socket::{AddressFamily, SockaddrLike, SockaddrStorage};
 use crate::{Errno, Result};
 
 /// Describes a single address for an interface as returned by `getifaddrs`.
@@ -32,6 +32,60 @@ pub struct InterfaceAddress {
     pub destination: Option<SockaddrStorage>,
 }
 
+/// Link-level counters of an interface, as found in `struct rtnl_link_stats`.
+///
+/// The kernel keeps these as 32-bit values, so they wrap around on busy
+/// interfaces.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LinkStats {
+    /// Total packets received
+    pub rx_packets: u32,
+    /// Total packets transmitted
+    pub tx_packets: u32,
+    /// Total bytes received
+    pub rx_bytes: u32,
+    /// Total bytes transmitted
+    pub tx_bytes: u32,
+    /// Bad packets received
+    pub rx_errors: u32,
+    /// Packet transmit problems
+    pub tx_errors: u32,
+    /// Packets received but dropped
+    pub rx_dropped: u32,
+    /// Packets dropped on transmit
+    pub tx_dropped: u32,
+}
+
+#[cfg(target_os = "linux")]
+impl LinkStats {
+    /// Decode the statistics that `getifaddrs` attaches to `AF_PACKET`
+    /// entries.
+    fn from_libc_ifaddrs(info: &libc::ifaddrs) -> Option<LinkStats> {
+        if info.ifa_addr.is_null()
+            || unsafe { (*info.ifa_addr).sa_family } as libc::c_int
+                != libc::AF_PACKET
+            || info.ifa_data.is_null()
+        {
+            return None;
+        }
+        // `struct rtnl_link_stats` is an array of `__u32` counters, and its
+        // leading members have been stable since the beginning.
+        let data = info.ifa_data as *const u32;
+        let field = |i| unsafe { data.add(i).read_unaligned() };
+        Some(LinkStats {
+            rx_packets: field(0),
+            tx_packets: field(1),
+            rx_bytes: field(2),
+            tx_bytes: field(3),
+            rx_errors: field(4),
+            tx_errors: field(5),
+            rx_dropped: field(6),
+            tx_dropped: field(7),
+        })
+    }
+}
+
 cfg_if! {
     if #[cfg(any(linux_android, target_os = "emscripten", target_os = "fuchsia"))] {
         fn get_ifu_from_sockaddr(info: &libc::ifaddrs) -> *const libc::sockaddr {
@@ -131,6 +185,22 @@ impl Drop for InterfaceAddressIterator {
     }
 }
 
+impl InterfaceAddressIterator {
+    /// Only yield the addresses whose `address` belongs to `family`.
+    ///
+    /// Entries without an address, or with an address of an unsupported
+    /// family, are skipped.
+    pub fn filter_family(
+        self,
+        family: AddressFamily,
+    ) -> impl Iterator<Item = InterfaceAddress> {
+        self.filter(move |ifaddr| {
+            ifaddr.address.as_ref().and_then(SockaddrLike::family)
+                == Some(family)
+        })
+    }
+}
+
 impl Iterator for InterfaceAddressIterator {
     type Item = InterfaceAddress;
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
@@ -153,6 +223,10 @@ impl Iterator for InterfaceAddressIterator {
 /// `address`, `netmask`, `broadcast`, and `destination`. For any entry not
 /// supported, the returned list will contain a `None` entry.
 ///
+/// Each yielded `InterfaceAddress` owns its data, so the results may be
+/// collected and kept after the iterator, and the underlying list, are
+/// dropped.
+///
 /// # Example
 /// ```
 /// let addrs = nix::ifaddrs::getifaddrs().unwrap();
@@ -181,6 +255,35 @@ pub fn getifaddrs() -> Result<InterfaceAddressIterator> {
     }
 }
 
+/// Get the link statistics of the interface named `interface_name`.
+///
+/// The statistics come from the link-layer (`AF_PACKET`) entry that
+/// `getifaddrs` reports for the interface.  Returns `None` if there is no such
+/// interface, or if it has no statistics.
+///
+/// # Example
+/// ```
+/// # use nix::ifaddrs::link_stats;
+/// if let Some(stats) = link_stats("lo").unwrap() {
+///     println!("lo received {} packets", stats.rx_packets);
+/// }
+/// ```
+#[cfg(target_os = "linux")]
+pub fn link_stats(interface_name: &str) -> Result<Option<LinkStats>> {
+    let addrs = getifaddrs()?;
+    let mut next = addrs.base;
+    while let Some(info) = unsafe { next.as_ref() } {
+        let name = unsafe { ffi::CStr::from_ptr(info.ifa_name) };
+        if name.to_bytes() == interface_name.as_bytes() {
+            if let Some(stats) = LinkStats::from_libc_ifaddrs(info) {
+                return Ok(Some(stats));
+            }
+        }
+        next = info.ifa_next;
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +317,53 @@ mod tests {
         }
         panic!("No address?");
     }
+
+    // Ensures the collected results outlive the iterator and may be sent to
+    // another thread.
+    #[test]
+    fn test_getifaddrs_owned() {
+        let addrs: Vec<InterfaceAddress> = getifaddrs().unwrap().collect();
+        let names = std::thread::spawn(move || {
+            addrs
+                .into_iter()
+                .map(|a| a.interface_name)
+                .collect::<Vec<_>>()
+        })
+        .join()
+        .unwrap();
+        assert!(!names.is_empty());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_getifaddrs_statistics() {
+        let lo = getifaddrs()
+            .unwrap()
+            .filter_family(AddressFamily::Packet)
+            .find(|a| a.flags.contains(InterfaceFlags::IFF_LOOPBACK))
+            .unwrap();
+        let stats = link_stats(&lo.interface_name).unwrap().unwrap();
+        // Every packet is at least one byte long
+        assert!(stats.rx_bytes >= stats.rx_packets);
+        assert!(stats.tx_bytes >= stats.tx_packets);
+
+        assert_eq!(link_stats("no-such-interface"), Ok(None));
+    }
+
+    #[test]
+    fn test_getifaddrs_filter_family() {
+        use crate::sys::socket::SockaddrIn;
+
+        let loopback: Vec<InterfaceAddress> = getifaddrs()
+            .unwrap()
+            .filter_family(AddressFamily::Inet)
+            .filter(|a| a.flags.contains(InterfaceFlags::IFF_LOOPBACK))
+            .collect();
+        // The iterator, and the list it walked, are gone by now
+        let addr = loopback[0].address.as_ref().unwrap();
+        assert_eq!(
+            addr.as_sockaddr_in(),
+            Some(&SockaddrIn::new(127, 0, 0, 1, 0))
+        );
+    }
 }
@@ -29,6 +29,7 @@
 //! * `pthread` - POSIX threads
 //! * `ptrace` - Process tracing and debugging
 //! * `quota` - File system quotas
+//! * `random` - Obtain random bytes from the operating system
 //! * `reboot` - Reboot the system
 //! * `resource` - Process resource limits
 //! * `sched` - Manipulate process's scheduling
@@ -75,6 +76,7 @@
         feature = "pthread",
         feature = "ptrace",
         feature = "quota",
+        feature = "random",
         feature = "reboot",
         feature = "resource",
         feature = "sched",
@@ -244,6 +246,14 @@ pub trait NixPath {
     fn with_nix_path<T, F>(&self, f: F) -> Result<T>
     where
         F: FnOnce(&CStr) -> T;
+
+    /// Copy this path into an owned `CString`.
+    ///
+    /// Useful to keep a path around for later calls.  Fails with `EINVAL` if
+    /// the path contains an interior NUL byte.
+    fn to_cstring(&self) -> Result<CString> {
+        self.with_nix_path(CStr::to_owned)
+    }
 }
 
 impl NixPath for str {
@@ -318,6 +328,14 @@ impl NixPath for [u8] {
         // https://docs.rs/compiler_builtins/latest/compiler_builtins/probestack/index.html
         const MAX_STACK_ALLOCATION: usize = 1024;
 
+        // A slice that is already NUL-terminated can be used in place.
+        if self.last() == Some(&0) {
+            return match CStr::from_bytes_with_nul(self) {
+                Ok(s) => Ok(f(s)),
+                Err(_) => Err(Errno::EINVAL),
+            };
+        }
+
         if self.len() >= MAX_STACK_ALLOCATION {
             return with_nix_path_allocating(self, f);
         }
@@ -339,6 +357,40 @@ impl NixPath for [u8] {
     }
 }
 
+impl NixPath for CString {
+    fn is_empty(&self) -> bool {
+        NixPath::is_empty(self.as_c_str())
+    }
+
+    fn len(&self) -> usize {
+        NixPath::len(self.as_c_str())
+    }
+
+    fn with_nix_path<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&CStr) -> T,
+    {
+        self.as_c_str().with_nix_path(f)
+    }
+}
+
+impl NixPath for Vec<u8> {
+    fn is_empty(&self) -> bool {
+        NixPath::is_empty(self.as_slice())
+    }
+
+    fn len(&self) -> usize {
+        NixPath::len(self.as_slice())
+    }
+
+    fn with_nix_path<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&CStr) -> T,
+    {
+        self.as_slice().with_nix_path(f)
+    }
+}
+
 #[cold]
 #[inline(never)]
 fn with_nix_path_allocating<T, F>(from: &[u8], f: F) -> Result<T>
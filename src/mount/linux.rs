@@ -1,6 +1,12 @@
 use crate::errno::Errno;
 use crate::{NixPath, Result};
 use libc::{self, c_int, c_ulong};
+#[cfg(target_os = "linux")]
+use libc::c_uint;
+#[cfg(target_os = "linux")]
+use std::ffi::CStr;
+#[cfg(target_os = "linux")]
+use std::os::unix::io::{AsFd, AsRawFd, FromRawFd, OwnedFd};
 
 libc_bitflags!(
     /// Used with [`mount`].
@@ -76,7 +82,7 @@ libc_bitflags!(
 );
 
 libc_bitflags!(
-    /// Used with [`umount2].
+    /// Used with [`umount2`].
     pub struct MntFlags: c_int {
         /// Attempt to unmount even if still in use, aborting pending requests.
         MNT_FORCE;
@@ -150,3 +156,319 @@ pub fn umount2<P: ?Sized + NixPath>(target: &P, flags: MntFlags) -> Result<()> {
 
     Errno::result(res).map(drop)
 }
+
+/// Make the tree at `source` also visible at `target`.
+///
+/// If `recursive` is true, the mounts below `source` are bind-mounted as well
+/// (`MS_BIND | MS_REC`).
+///
+/// # See Also
+/// [Creating a bind mount](https://man7.org/linux/man-pages/man2/mount.2.html)
+pub fn bind_mount<P1: ?Sized + NixPath, P2: ?Sized + NixPath>(
+    source: &P1,
+    target: &P2,
+    recursive: bool,
+) -> Result<()> {
+    let mut flags = MsFlags::MS_BIND;
+    if recursive {
+        flags |= MsFlags::MS_REC;
+    }
+    mount(Some(source), target, None::<&[u8]>, flags, None::<&[u8]>)
+}
+
+/// Move the mount at `source` to `target` (`MS_MOVE`).
+///
+/// This is the classic `mount(2)` way of moving a mount.  On Linux, see also
+/// `move_mount` for the file descriptor based API.
+///
+/// # See Also
+/// [Moving a mount](https://man7.org/linux/man-pages/man2/mount.2.html)
+pub fn mount_move<P1: ?Sized + NixPath, P2: ?Sized + NixPath>(
+    source: &P1,
+    target: &P2,
+) -> Result<()> {
+    mount(
+        Some(source),
+        target,
+        None::<&[u8]>,
+        MsFlags::MS_MOVE,
+        None::<&[u8]>,
+    )
+}
+
+/// Stop propagating mount and unmount events between the mount at `target`
+/// and its peers (`MS_PRIVATE`).
+///
+/// If `recursive` is true, this also applies to the mounts below `target`.
+///
+/// # See Also
+/// [mount_namespaces(7)](https://man7.org/linux/man-pages/man7/mount_namespaces.7.html)
+pub fn make_private<P: ?Sized + NixPath>(
+    target: &P,
+    recursive: bool,
+) -> Result<()> {
+    set_propagation(target, MountPropagation::Private, recursive)
+}
+
+/// Propagate mount and unmount events between the mount at `target` and its
+/// peers (`MS_SHARED`).
+///
+/// If `recursive` is true, this also applies to the mounts below `target`.
+///
+/// # See Also
+/// [mount_namespaces(7)](https://man7.org/linux/man-pages/man7/mount_namespaces.7.html)
+pub fn make_shared<P: ?Sized + NixPath>(
+    target: &P,
+    recursive: bool,
+) -> Result<()> {
+    set_propagation(target, MountPropagation::Shared, recursive)
+}
+
+/// Propagation type of a mount, as changed by [`set_propagation`].
+///
+/// # See Also
+/// [mount_namespaces(7)](https://man7.org/linux/man-pages/man7/mount_namespaces.7.html)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MountPropagation {
+    /// Mount and unmount events propagate to and from the peers of the mount
+    /// (`MS_SHARED`).
+    Shared,
+    /// Mount and unmount events do not propagate to or from the mount
+    /// (`MS_PRIVATE`).
+    Private,
+    /// Mount and unmount events propagate from the master peer group into the
+    /// mount, but not the other way (`MS_SLAVE`).
+    Slave,
+    /// Like `Private`, and the mount cannot be the source of a bind mount
+    /// (`MS_UNBINDABLE`).
+    Unbindable,
+}
+
+impl From<MountPropagation> for MsFlags {
+    fn from(propagation: MountPropagation) -> Self {
+        match propagation {
+            MountPropagation::Shared => MsFlags::MS_SHARED,
+            MountPropagation::Private => MsFlags::MS_PRIVATE,
+            MountPropagation::Slave => MsFlags::MS_SLAVE,
+            MountPropagation::Unbindable => MsFlags::MS_UNBINDABLE,
+        }
+    }
+}
+
+/// Change the propagation type of the mount at `target`.
+///
+/// The kernel rejects propagation flags combined with other mount flags, so
+/// this must be a separate call from the one creating the mount.  If
+/// `recursive` is true, this also applies to the mounts below `target`.
+///
+/// # See Also
+/// [Changing the propagation type of an existing mount](https://man7.org/linux/man-pages/man2/mount.2.html)
+pub fn set_propagation<P: ?Sized + NixPath>(
+    target: &P,
+    propagation: MountPropagation,
+    recursive: bool,
+) -> Result<()> {
+    let mut flags = MsFlags::from(propagation);
+    if recursive {
+        flags |= MsFlags::MS_REC;
+    }
+    mount(None::<&[u8]>, target, None::<&[u8]>, flags, None::<&[u8]>)
+}
+
+#[cfg(target_os = "linux")]
+libc_bitflags!(
+    /// Used with [`fsopen`].
+    pub struct FsOpenFlags: c_uint {
+        /// Set the close-on-exec flag on the returned file descriptor.
+        FSOPEN_CLOEXEC;
+    }
+);
+
+#[cfg(target_os = "linux")]
+libc_enum! {
+    /// Operation performed by [`fsconfig`].
+    #[repr(u32)]
+    #[non_exhaustive]
+    pub enum FsConfigCmd {
+        /// Set the flag parameter `key`.
+        FSCONFIG_SET_FLAG,
+        /// Set the parameter `key` to the string `value`.
+        FSCONFIG_SET_STRING,
+        /// Set the parameter `key` to a binary blob.
+        FSCONFIG_SET_BINARY,
+        /// Set the parameter `key` to the path `value`, relative to the
+        /// directory file descriptor `aux`.
+        FSCONFIG_SET_PATH,
+        /// Like `FSCONFIG_SET_PATH`, but allows an empty path.
+        FSCONFIG_SET_PATH_EMPTY,
+        /// Set the parameter `key` to the file descriptor `aux`.
+        FSCONFIG_SET_FD,
+        /// Create the superblock, once all parameters are set.
+        FSCONFIG_CMD_CREATE,
+        /// Reconfigure the superblock with the parameters set.
+        FSCONFIG_CMD_RECONFIGURE,
+        /// Like `FSCONFIG_CMD_CREATE`, but fail rather than reuse an existing
+        /// superblock.
+        FSCONFIG_CMD_CREATE_EXCL,
+    }
+}
+
+#[cfg(target_os = "linux")]
+libc_bitflags!(
+    /// Used with [`fsmount`].
+    pub struct FsMountFlags: c_uint {
+        /// Set the close-on-exec flag on the returned file descriptor.
+        FSMOUNT_CLOEXEC;
+    }
+);
+
+#[cfg(target_os = "linux")]
+libc_bitflags!(
+    /// Attributes of a mount created by [`fsmount`].
+    pub struct MountAttrFlags: u64 {
+        /// Mount read-only.
+        MOUNT_ATTR_RDONLY;
+        /// Ignore suid and sgid bits.
+        MOUNT_ATTR_NOSUID;
+        /// Disallow access to device special files.
+        MOUNT_ATTR_NODEV;
+        /// Disallow program execution.
+        MOUNT_ATTR_NOEXEC;
+        /// Do not update access times.
+        MOUNT_ATTR_NOATIME;
+        /// Always update access times.
+        MOUNT_ATTR_STRICTATIME;
+        /// Do not update directory access times.
+        MOUNT_ATTR_NODIRATIME;
+        /// Do not follow symlinks.
+        MOUNT_ATTR_NOSYMFOLLOW;
+    }
+);
+
+#[cfg(target_os = "linux")]
+libc_bitflags!(
+    /// Used with [`move_mount`].
+    pub struct MoveMountFlags: c_uint {
+        /// Follow symlinks on the source path.
+        MOVE_MOUNT_F_SYMLINKS;
+        /// Follow automounts on the source path.
+        MOVE_MOUNT_F_AUTOMOUNTS;
+        /// Use the source file descriptor itself if the path is empty.
+        MOVE_MOUNT_F_EMPTY_PATH;
+        /// Follow symlinks on the target path.
+        MOVE_MOUNT_T_SYMLINKS;
+        /// Follow automounts on the target path.
+        MOVE_MOUNT_T_AUTOMOUNTS;
+        /// Use the target file descriptor itself if the path is empty.
+        MOVE_MOUNT_T_EMPTY_PATH;
+        /// Set the sharing group of the target from the source.
+        MOVE_MOUNT_SET_GROUP;
+        /// Mount beneath the top mount of the target.
+        MOVE_MOUNT_BENEATH;
+    }
+);
+
+/// Create a file system context for a file system of type `fstype`.
+///
+/// The context is configured with [`fsconfig`], then turned into a mount with
+/// [`fsmount`].
+///
+/// # See Also
+/// [`fsopen`](https://man7.org/linux/man-pages/man2/fsopen.2.html)
+#[cfg(target_os = "linux")]
+pub fn fsopen(fstype: &CStr, flags: FsOpenFlags) -> Result<OwnedFd> {
+    let res = unsafe {
+        libc::syscall(libc::SYS_fsopen, fstype.as_ptr(), flags.bits())
+    };
+
+    Errno::result(res).map(|fd| unsafe { OwnedFd::from_raw_fd(fd as c_int) })
+}
+
+/// Configure a file system context created by [`fsopen`].
+///
+/// The meaning of `key`, `value` and `aux` depends on `cmd`.  Binary values
+/// for `FSCONFIG_SET_BINARY` can't be passed as a `CStr`, and are not
+/// supported.
+///
+/// # See Also
+/// [`fsconfig`](https://man7.org/linux/man-pages/man2/fsconfig.2.html)
+#[cfg(target_os = "linux")]
+pub fn fsconfig<Fd: AsFd>(
+    fd: Fd,
+    cmd: FsConfigCmd,
+    key: Option<&CStr>,
+    value: Option<&CStr>,
+    aux: c_int,
+) -> Result<()> {
+    let key = key.map_or(std::ptr::null(), CStr::as_ptr);
+    let value = value.map_or(std::ptr::null(), CStr::as_ptr);
+    let res = unsafe {
+        libc::syscall(
+            libc::SYS_fsconfig,
+            fd.as_fd().as_raw_fd(),
+            cmd as c_uint,
+            key,
+            value,
+            aux,
+        )
+    };
+
+    Errno::result(res).map(drop)
+}
+
+/// Create a detached mount from a file system context.
+///
+/// The mount can then be attached with [`move_mount`].
+///
+/// # See Also
+/// [`fsmount`](https://man7.org/linux/man-pages/man2/fsmount.2.html)
+#[cfg(target_os = "linux")]
+pub fn fsmount<Fd: AsFd>(
+    fd: Fd,
+    flags: FsMountFlags,
+    attr: MountAttrFlags,
+) -> Result<OwnedFd> {
+    let res = unsafe {
+        libc::syscall(
+            libc::SYS_fsmount,
+            fd.as_fd().as_raw_fd(),
+            flags.bits(),
+            attr.bits() as c_uint,
+        )
+    };
+
+    Errno::result(res).map(|fd| unsafe { OwnedFd::from_raw_fd(fd as c_int) })
+}
+
+/// Move a mount from one place to another, or attach a detached mount.
+///
+/// # See Also
+/// [`move_mount`](https://man7.org/linux/man-pages/man2/move_mount.2.html)
+#[cfg(target_os = "linux")]
+pub fn move_mount<
+    Fd1: AsFd,
+    P1: ?Sized + NixPath,
+    Fd2: AsFd,
+    P2: ?Sized + NixPath,
+>(
+    from_dirfd: Fd1,
+    from_path: &P1,
+    to_dirfd: Fd2,
+    to_path: &P2,
+    flags: MoveMountFlags,
+) -> Result<()> {
+    let res = from_path.with_nix_path(|from| {
+        to_path.with_nix_path(|to| unsafe {
+            libc::syscall(
+                libc::SYS_move_mount,
+                from_dirfd.as_fd().as_raw_fd(),
+                from.as_ptr(),
+                to_dirfd.as_fd().as_raw_fd(),
+                to.as_ptr(),
+                flags.bits(),
+            )
+        })
+    })??;
+
+    Errno::result(res).map(drop)
+}
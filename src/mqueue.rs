@@ -216,6 +216,10 @@ feature! {
     use crate::sys::time::TimeSpec;
     /// Receive a message from a message queue with a timeout
     ///
+    /// If the queue is empty, wait until `abstime`, an absolute
+    /// `CLOCK_REALTIME` deadline, for a message and fail with `ETIMEDOUT` if
+    /// none arrives by then.
+    ///
     /// See also ['mq_timedreceive(2)'](https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_receive.html)
     pub fn mq_timedreceive(
         mqdes: &MqdT,
@@ -235,6 +239,31 @@ feature! {
         };
         Errno::result(res).map(|r| r as usize)
     }
+
+    /// Send a message to a message queue with a timeout
+    ///
+    /// If the queue is full, wait until `abstime`, an absolute
+    /// `CLOCK_REALTIME` deadline, for room to become available and fail with
+    /// `ETIMEDOUT` if there is none by then.
+    ///
+    /// See also [`mq_timedsend(2)`](https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_send.html)
+    pub fn mq_timedsend(
+        mqdes: &MqdT,
+        message: &[u8],
+        msg_prio: u32,
+        abstime: &TimeSpec,
+    ) -> Result<()> {
+        let res = unsafe {
+            libc::mq_timedsend(
+                mqdes.0,
+                message.as_ptr().cast(),
+                message.len(),
+                msg_prio,
+                abstime.as_ref(),
+            )
+        };
+        Errno::result(res).map(drop)
+    }
 }
 
 /// Send a message to a message queue
@@ -0,0 +1,365 @@
+//! Network address and service translation.
+//!
+//! Uses the POSIX functions `getaddrinfo` and `getnameinfo` to translate
+//! between host and service names and socket addresses.
+
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
+use std::{error, fmt, mem, ptr};
+
+use libc::{c_char, c_int};
+
+use crate::errno::Errno;
+use crate::sys::socket::{
+    AddressFamily, SockProtocol, SockType, SockaddrLike, SockaddrStorage,
+};
+
+// NI_MAXHOST and NI_MAXSERV aren't defined by libc on every platform.
+const NI_MAXHOST: usize = 1025;
+const NI_MAXSERV: usize = 32;
+
+/// Errors returned by [`getaddrinfo`] and [`getnameinfo`].
+///
+/// These functions report failures with their own `EAI_*` codes, rather
+/// than through `errno`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum GaiError {
+    /// The name could not be resolved at this time.  Future attempts may
+    /// succeed.
+    Again,
+    /// The flags had an invalid value.
+    BadFlags,
+    /// A non-recoverable error occurred.
+    Fail,
+    /// The address family was not recognized, or the address length was
+    /// invalid for the specified family.
+    Family,
+    /// There was a memory allocation failure.
+    Memory,
+    /// The name does not resolve for the supplied parameters.
+    NoName,
+    /// The service passed was not recognized for the specified socket type.
+    Service,
+    /// The intended socket type was not recognized.
+    SockType,
+    /// An argument buffer overflowed.
+    Overflow,
+    /// A system error occurred.
+    System(Errno),
+    /// An error code not known to nix.
+    Unknown(c_int),
+}
+
+impl GaiError {
+    /// Convert a non-zero return value of `getaddrinfo` or `getnameinfo` into
+    /// a `GaiError`.
+    ///
+    /// `EAI_SYSTEM` is converted using the current value of `errno`.
+    pub fn from_raw(code: c_int) -> Self {
+        match code {
+            libc::EAI_AGAIN => GaiError::Again,
+            libc::EAI_BADFLAGS => GaiError::BadFlags,
+            libc::EAI_FAIL => GaiError::Fail,
+            libc::EAI_FAMILY => GaiError::Family,
+            libc::EAI_MEMORY => GaiError::Memory,
+            libc::EAI_NONAME => GaiError::NoName,
+            libc::EAI_SERVICE => GaiError::Service,
+            libc::EAI_SOCKTYPE => GaiError::SockType,
+            libc::EAI_OVERFLOW => GaiError::Overflow,
+            libc::EAI_SYSTEM => GaiError::System(Errno::last()),
+            code => GaiError::Unknown(code),
+        }
+    }
+
+    /// Return the raw `EAI_*` code for this error.
+    pub fn as_raw(self) -> c_int {
+        match self {
+            GaiError::Again => libc::EAI_AGAIN,
+            GaiError::BadFlags => libc::EAI_BADFLAGS,
+            GaiError::Fail => libc::EAI_FAIL,
+            GaiError::Family => libc::EAI_FAMILY,
+            GaiError::Memory => libc::EAI_MEMORY,
+            GaiError::NoName => libc::EAI_NONAME,
+            GaiError::Service => libc::EAI_SERVICE,
+            GaiError::SockType => libc::EAI_SOCKTYPE,
+            GaiError::Overflow => libc::EAI_OVERFLOW,
+            GaiError::System(_) => libc::EAI_SYSTEM,
+            GaiError::Unknown(code) => code,
+        }
+    }
+
+    /// Return a human-readable description of this error, as given by
+    /// `gai_strerror`.
+    ///
+    /// For [`GaiError::System`], the description of the underlying `errno`
+    /// is returned instead.
+    pub fn desc(self) -> Cow<'static, str> {
+        if let GaiError::System(errno) = self {
+            return Cow::Borrowed(errno.desc());
+        }
+        // gai_strerror returns a pointer to a static string
+        let msg = unsafe { libc::gai_strerror(self.as_raw()) };
+        if msg.is_null() {
+            return Cow::Borrowed("Unknown error");
+        }
+        unsafe { CStr::from_ptr(msg) }.to_string_lossy()
+    }
+}
+
+impl error::Error for GaiError {}
+
+impl fmt::Display for GaiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}: {}", self, self.desc())
+    }
+}
+
+libc_bitflags! {
+    /// Flags for use with [`AddrInfoHints::flags`].
+    pub struct AddrInfoFlags: c_int {
+        /// The returned addresses are intended for use with `bind`.  If the
+        /// host is `None`, they will be wildcard addresses.
+        #[cfg(not(any(solarish, target_os = "haiku")))]
+        AI_PASSIVE;
+        /// Request the canonical name of the host.
+        AI_CANONNAME;
+        /// The host must be a numeric address string; no name resolution is
+        /// performed.
+        #[cfg(not(any(solarish, target_os = "haiku")))]
+        AI_NUMERICHOST;
+        /// The service must be a numeric port string; no name resolution is
+        /// performed.
+        #[cfg(not(any(solarish, target_os = "haiku")))]
+        AI_NUMERICSERV;
+        /// If no IPv6 addresses are found while looking for `AF_INET6`,
+        /// return IPv4-mapped IPv6 addresses instead.
+        #[cfg(any(linux_android, freebsdlike, apple_targets))]
+        AI_V4MAPPED;
+        /// Together with `AI_V4MAPPED`, return both IPv6 and IPv4-mapped IPv6
+        /// addresses.
+        #[cfg(any(linux_android, freebsdlike, apple_targets))]
+        AI_ALL;
+        /// Only return addresses of a family for which the system has a
+        /// configured address.
+        #[cfg(not(any(solarish, target_os = "haiku")))]
+        AI_ADDRCONFIG;
+    }
+}
+
+libc_bitflags! {
+    /// Flags for use with [`getnameinfo`].
+    pub struct NameInfoFlags: c_int {
+        /// Return only the hostname part of the fully qualified domain name
+        /// for local hosts.
+        NI_NOFQDN;
+        /// Return the numeric form of the host address.
+        NI_NUMERICHOST;
+        /// Return an error if the host name cannot be determined.
+        NI_NAMEREQD;
+        /// Return the numeric form of the service address.
+        NI_NUMERICSERV;
+        /// The service is datagram-based rather than stream-based.
+        NI_DGRAM;
+    }
+}
+
+/// Criteria for selecting the addresses returned by [`getaddrinfo`].
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct AddrInfoHints(libc::addrinfo);
+
+impl AddrInfoHints {
+    /// Create hints that accept addresses of any family, socket type and
+    /// protocol.
+    pub fn new() -> Self {
+        // safety: all-zero is the documented way to initialize the hints,
+        // and the pointers within are null.
+        Self(unsafe { mem::MaybeUninit::zeroed().assume_init() })
+    }
+
+    /// Set the flags, completely overwriting any existing flags.
+    pub fn flags(mut self, flags: AddrInfoFlags) -> Self {
+        self.0.ai_flags = flags.bits();
+        self
+    }
+
+    /// Only return addresses of the given family.
+    pub fn family(mut self, family: AddressFamily) -> Self {
+        self.0.ai_family = family as c_int;
+        self
+    }
+
+    /// Only return addresses usable with the given socket type.
+    pub fn socktype(mut self, socktype: SockType) -> Self {
+        self.0.ai_socktype = socktype as c_int;
+        self
+    }
+
+    /// Only return addresses usable with the given protocol.
+    pub fn protocol(mut self, protocol: SockProtocol) -> Self {
+        self.0.ai_protocol = protocol as c_int;
+        self
+    }
+}
+
+impl Default for AddrInfoHints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Describes a single address returned by `getaddrinfo`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddrInfo {
+    /// Address family of the socket
+    pub family: Option<AddressFamily>,
+    /// Socket type, if known to nix
+    pub socktype: Option<SockType>,
+    /// Raw protocol number for the socket
+    pub protocol: c_int,
+    /// Socket address for the host and service
+    pub address: Option<SockaddrStorage>,
+    /// Canonical name of the host, if requested with `AI_CANONNAME`
+    pub canonname: Option<String>,
+}
+
+impl AddrInfo {
+    /// Create an `AddrInfo` from the libc struct.
+    fn from_libc_addrinfo(info: &libc::addrinfo) -> AddrInfo {
+        let address = unsafe {
+            SockaddrStorage::from_raw(info.ai_addr, Some(info.ai_addrlen))
+        };
+        let canonname = if info.ai_canonname.is_null() {
+            None
+        } else {
+            let name = unsafe { CStr::from_ptr(info.ai_canonname) };
+            Some(name.to_string_lossy().into_owned())
+        };
+
+        AddrInfo {
+            family: AddressFamily::from_i32(info.ai_family),
+            socktype: SockType::try_from(info.ai_socktype).ok(),
+            protocol: info.ai_protocol,
+            address,
+            canonname,
+        }
+    }
+}
+
+/// Holds the results of `getaddrinfo`.
+///
+/// Use the function [`getaddrinfo`] to create this Iterator.  The underlying
+/// list is freed as soon as the Iterator goes out of scope.
+#[derive(Debug)]
+pub struct AddrInfoIter {
+    base: *mut libc::addrinfo,
+    next: *mut libc::addrinfo,
+}
+
+impl Drop for AddrInfoIter {
+    fn drop(&mut self) {
+        unsafe { libc::freeaddrinfo(self.base) };
+    }
+}
+
+impl Iterator for AddrInfoIter {
+    type Item = AddrInfo;
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        match unsafe { self.next.as_ref() } {
+            Some(info) => {
+                self.next = info.ai_next;
+                Some(AddrInfo::from_libc_addrinfo(info))
+            }
+            None => None,
+        }
+    }
+}
+
+fn to_cstring(s: Option<&str>) -> Result<Option<CString>, GaiError> {
+    s.map(CString::new)
+        .transpose()
+        .map_err(|_| GaiError::System(Errno::EINVAL))
+}
+
+/// Translate a host and service name into a list of socket addresses.
+///
+/// At least one of `host` and `service` must be given.
+///
+/// # Example
+/// ```
+/// use nix::net::addrinfo::getaddrinfo;
+///
+/// for ai in getaddrinfo(Some("localhost"), Some("80"), None).unwrap() {
+///     if let Some(address) = ai.address {
+///         println!("localhost resolves to {}", address);
+///     }
+/// }
+/// ```
+///
+/// # See Also
+/// [getaddrinfo(3)](https://pubs.opengroup.org/onlinepubs/9699919799/functions/getaddrinfo.html)
+pub fn getaddrinfo(
+    host: Option<&str>,
+    service: Option<&str>,
+    hints: Option<AddrInfoHints>,
+) -> Result<AddrInfoIter, GaiError> {
+    let host = to_cstring(host)?;
+    let service = to_cstring(service)?;
+    let hints = hints.map(|h| h.0);
+    let mut res = mem::MaybeUninit::<*mut libc::addrinfo>::uninit();
+
+    let ret = unsafe {
+        libc::getaddrinfo(
+            host.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+            service.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+            hints.as_ref().map_or(ptr::null(), |h| h as *const _),
+            res.as_mut_ptr(),
+        )
+    };
+    if ret != 0 {
+        return Err(GaiError::from_raw(ret));
+    }
+
+    let res = unsafe { res.assume_init() };
+    Ok(AddrInfoIter {
+        base: res,
+        next: res,
+    })
+}
+
+/// Translate a socket address into a host and service name.
+///
+/// Returns a `(host, service)` pair.
+///
+/// # See Also
+/// [getnameinfo(3)](https://pubs.opengroup.org/onlinepubs/9699919799/functions/getnameinfo.html)
+pub fn getnameinfo<S: SockaddrLike>(
+    addr: &S,
+    flags: NameInfoFlags,
+) -> Result<(String, String), GaiError> {
+    let mut host = [0 as c_char; NI_MAXHOST];
+    let mut service = [0 as c_char; NI_MAXSERV];
+
+    let ret = unsafe {
+        libc::getnameinfo(
+            addr.as_ptr(),
+            addr.len(),
+            host.as_mut_ptr(),
+            host.len() as _,
+            service.as_mut_ptr(),
+            service.len() as _,
+            flags.bits(),
+        )
+    };
+    if ret != 0 {
+        return Err(GaiError::from_raw(ret));
+    }
+
+    let host = unsafe { CStr::from_ptr(host.as_ptr()) };
+    let service = unsafe { CStr::from_ptr(service.as_ptr()) };
+    Ok((
+        host.to_string_lossy().into_owned(),
+        service.to_string_lossy().into_owned(),
+    ))
+}
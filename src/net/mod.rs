@@ -1,4 +1,5 @@
 //! Functionality involving network interfaces
+pub mod addrinfo;
 // To avoid clashing with the keyword "if", we use "if_" as the module name.
 // The original header is called "net/if.h".
 pub mod if_;
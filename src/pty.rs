@@ -244,6 +244,56 @@ pub fn unlockpt(fd: &PtyMaster) -> Result<()> {
     Ok(())
 }
 
+/// Get the foreground process group of a terminal with the `TIOCGPGRP` ioctl.
+///
+/// Unlike `tcgetpgrp`, which is only specified for the calling process's
+/// controlling terminal, this works on any terminal the platform allows, such
+/// as the master side of a pseudoterminal on Linux.
+///
+/// See also [`ioctl_tty(2)`](https://man7.org/linux/man-pages/man2/ioctl_tty.2.html).
+#[cfg(any(linux_android, freebsdlike, apple_targets))]
+pub fn tiocgpgrp<Fd: AsFd>(fd: Fd) -> Result<Pid> {
+    let mut pgid: libc::pid_t = 0;
+    let res = unsafe {
+        libc::ioctl(fd.as_fd().as_raw_fd(), libc::TIOCGPGRP, &mut pgid)
+    };
+    Errno::result(res).map(|_| Pid::from_raw(pgid))
+}
+
+/// Set the foreground process group of a terminal with the `TIOCSPGRP` ioctl.
+///
+/// The terminal must be the calling process's controlling terminal, and
+/// `pgid` a process group in the same session.
+///
+/// See also [`ioctl_tty(2)`](https://man7.org/linux/man-pages/man2/ioctl_tty.2.html).
+#[cfg(any(linux_android, freebsdlike, apple_targets))]
+pub fn tiocspgrp<Fd: AsFd>(fd: Fd, pgid: Pid) -> Result<()> {
+    let pgid: libc::pid_t = pgid.into();
+    let res = unsafe {
+        libc::ioctl(fd.as_fd().as_raw_fd(), libc::TIOCSPGRP, &pgid)
+    };
+    Errno::result(res).map(drop)
+}
+
+/// Prepare `fd` for a login session: start a new session, make `fd` its
+/// controlling terminal and duplicate it onto stdin, stdout and stderr
+/// (see [`login_tty(3)`](https://man7.org/linux/man-pages/man3/login_tty.3.html)).
+///
+/// This is typically called in a child process with the slave side of a
+/// pseudoterminal.  `fd` itself is closed once it has been duplicated, which
+/// is why it is taken by value.
+#[cfg(any(linux_android, bsd, target_os = "haiku", target_os = "hurd"))]
+pub fn login_tty(fd: OwnedFd) -> Result<()> {
+    let raw = fd.into_raw_fd();
+    if unsafe { libc::login_tty(raw) } < 0 {
+        let err = Errno::last();
+        // On failure, login_tty(3) does not close `fd`.
+        drop(unsafe { OwnedFd::from_raw_fd(raw) });
+        return Err(err);
+    }
+    Ok(())
+}
+
 /// Create a new pseudoterminal, returning the slave and master file descriptors
 /// in `OpenptyResult`
 /// (see [`openpty`](https://man7.org/linux/man-pages/man3/openpty.3.html)).
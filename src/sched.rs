@@ -164,6 +164,7 @@ mod sched_affinity {
     use crate::unistd::Pid;
     use crate::Result;
     use std::mem;
+    use std::ops::{BitAnd, BitOr, Sub};
 
     /// CpuSet represent a bit-mask of CPUs.
     /// CpuSets are used by sched_setaffinity and
@@ -232,6 +233,22 @@ mod sched_affinity {
 
             8 * bytes
         }
+
+        /// Iterate over the ids of the CPUs in this CpuSet, in increasing
+        /// order.
+        pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+            (0..CpuSet::count()).filter(move |&cpu| {
+                unsafe { libc::CPU_ISSET(cpu, &self.cpu_set) }
+            })
+        }
+
+        /// Return the number of CPUs in this CpuSet.
+        ///
+        /// Unlike [`CpuSet::count`], which is the capacity of any CpuSet,
+        /// this only counts the CPUs that are set.
+        pub fn count_set(&self) -> usize {
+            self.iter().count()
+        }
     }
 
     impl Default for CpuSet {
@@ -240,11 +257,58 @@ mod sched_affinity {
         }
     }
 
+    impl CpuSet {
+        /// Build the set of CPUs for which `f` holds, given whether each CPU
+        /// is in `self` and in `other`.
+        fn combine(&self, other: &CpuSet, f: fn(bool, bool) -> bool) -> CpuSet {
+            let mut res = CpuSet::new();
+            for cpu in 0..CpuSet::count() {
+                let (a, b) = unsafe {
+                    (
+                        libc::CPU_ISSET(cpu, &self.cpu_set),
+                        libc::CPU_ISSET(cpu, &other.cpu_set),
+                    )
+                };
+                if f(a, b) {
+                    unsafe { libc::CPU_SET(cpu, &mut res.cpu_set) };
+                }
+            }
+            res
+        }
+    }
+
+    /// The union of two CpuSets.
+    impl BitOr for CpuSet {
+        type Output = CpuSet;
+
+        fn bitor(self, rhs: CpuSet) -> CpuSet {
+            self.combine(&rhs, |a, b| a || b)
+        }
+    }
+
+    /// The intersection of two CpuSets.
+    impl BitAnd for CpuSet {
+        type Output = CpuSet;
+
+        fn bitand(self, rhs: CpuSet) -> CpuSet {
+            self.combine(&rhs, |a, b| a && b)
+        }
+    }
+
+    /// The CPUs of the first CpuSet that are not in the second.
+    impl Sub for CpuSet {
+        type Output = CpuSet;
+
+        fn sub(self, rhs: CpuSet) -> CpuSet {
+            self.combine(&rhs, |a, b| a && !b)
+        }
+    }
+
     /// `sched_setaffinity` set a thread's CPU affinity mask
     /// ([`sched_setaffinity(2)`](https://man7.org/linux/man-pages/man2/sched_setaffinity.2.html))
     ///
     /// `pid` is the thread ID to update.
-    /// If pid is zero, then the calling thread is updated.
+    /// If pid is `None` or zero, then the calling thread is updated.
     ///
     /// The `cpuset` argument specifies the set of CPUs on which the thread
     /// will be eligible to run.
@@ -255,16 +319,19 @@ mod sched_affinity {
     ///
     /// ```rust,no_run
     /// use nix::sched::{CpuSet, sched_setaffinity};
-    /// use nix::unistd::Pid;
     ///
     /// let mut cpu_set = CpuSet::new();
     /// cpu_set.set(0).unwrap();
-    /// sched_setaffinity(Pid::from_raw(0), &cpu_set).unwrap();
+    /// sched_setaffinity(None, &cpu_set).unwrap();
     /// ```
-    pub fn sched_setaffinity(pid: Pid, cpuset: &CpuSet) -> Result<()> {
+    pub fn sched_setaffinity<P: Into<Option<Pid>>>(
+        pid: P,
+        cpuset: &CpuSet,
+    ) -> Result<()> {
+        let pid = pid.into().map_or(0, Pid::as_raw);
         let res = unsafe {
             libc::sched_setaffinity(
-                pid.into(),
+                pid,
                 mem::size_of::<CpuSet>() as libc::size_t,
                 &cpuset.cpu_set,
             )
@@ -277,7 +344,7 @@ mod sched_affinity {
     /// ([`sched_getaffinity(2)`](https://man7.org/linux/man-pages/man2/sched_getaffinity.2.html))
     ///
     /// `pid` is the thread ID to check.
-    /// If pid is zero, then the calling thread is checked.
+    /// If pid is `None` or zero, then the calling thread is checked.
     ///
     /// Returned `cpuset` is the set of CPUs on which the thread
     /// is eligible to run.
@@ -288,18 +355,18 @@ mod sched_affinity {
     ///
     /// ```rust,no_run
     /// use nix::sched::sched_getaffinity;
-    /// use nix::unistd::Pid;
     ///
-    /// let cpu_set = sched_getaffinity(Pid::from_raw(0)).unwrap();
+    /// let cpu_set = sched_getaffinity(None).unwrap();
     /// if cpu_set.is_set(0).unwrap() {
     ///     println!("Current thread can run on CPU 0");
     /// }
     /// ```
-    pub fn sched_getaffinity(pid: Pid) -> Result<CpuSet> {
+    pub fn sched_getaffinity<P: Into<Option<Pid>>>(pid: P) -> Result<CpuSet> {
+        let pid = pid.into().map_or(0, Pid::as_raw);
         let mut cpuset = CpuSet::new();
         let res = unsafe {
             libc::sched_getaffinity(
-                pid.into(),
+                pid,
                 mem::size_of::<CpuSet>() as libc::size_t,
                 &mut cpuset.cpu_set,
             )
@@ -0,0 +1,63 @@
+//! Read the ELF auxiliary vector
+//!
+//! The kernel passes the auxiliary vector to every program it starts, to
+//! describe the process's environment: the page size, the CPU's capabilities,
+//! and so on.
+//!
+//! [Further reading](https://man7.org/linux/man-pages/man3/getauxval.3.html)
+use crate::errno::Errno;
+use crate::Result;
+use libc::c_ulong;
+
+/// Entries of the auxiliary vector, for use with [`getauxval`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+#[allow(non_camel_case_types)]
+pub enum AuxvType {
+    /// A bit mask of architecture-specific CPU capabilities.
+    AT_HWCAP,
+    /// A further bit mask of architecture-specific CPU capabilities.
+    AT_HWCAP2,
+    /// The system page size.
+    AT_PAGESZ,
+    /// Whether the program is running in secure-execution mode, such as for a
+    /// set-user-ID program.
+    AT_SECURE,
+    /// The address of sixteen random bytes provided by the kernel.
+    AT_RANDOM,
+}
+
+impl AuxvType {
+    const fn as_raw(self) -> c_ulong {
+        match self {
+            AuxvType::AT_HWCAP => libc::AT_HWCAP,
+            AuxvType::AT_HWCAP2 => libc::AT_HWCAP2,
+            AuxvType::AT_PAGESZ => libc::AT_PAGESZ,
+            AuxvType::AT_SECURE => libc::AT_SECURE,
+            AuxvType::AT_RANDOM => libc::AT_RANDOM,
+        }
+    }
+}
+
+/// Read an entry of the auxiliary vector.
+///
+/// Fails with `ENOENT` if the kernel did not provide the entry.
+///
+/// # Example
+/// ```
+/// # use nix::sys::auxv::{getauxval, AuxvType};
+/// let page_size = getauxval(AuxvType::AT_PAGESZ).unwrap();
+/// assert!(page_size.is_power_of_two());
+/// ```
+pub fn getauxval(type_: AuxvType) -> Result<u64> {
+    Errno::clear();
+    let res = unsafe { libc::getauxval(type_.as_raw()) };
+    // 0 is both a valid value and the error sentinel.
+    if res == 0 && Errno::last_raw() != 0 {
+        Err(Errno::last())
+    } else {
+        // The cast is not unnecessary on all platforms.
+        #[allow(clippy::unnecessary_cast)]
+        Ok(res as u64)
+    }
+}
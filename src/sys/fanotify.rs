@@ -14,8 +14,10 @@ use crate::errno::Errno;
 use crate::fcntl::OFlag;
 use crate::unistd::{close, read, write};
 use crate::{NixPath, Result};
+use std::ffi::{CStr, OsStr, OsString};
 use std::marker::PhantomData;
 use std::mem::{size_of, MaybeUninit};
+use std::os::unix::ffi::OsStrExt;
 use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
 use std::ptr;
 
@@ -118,6 +120,19 @@ libc_bitflags! {
         FAN_REPORT_PIDFD;
         /// Make `FanotifyEvent::pid` return thread id. Since Linux 4.20.
         FAN_REPORT_TID;
+
+        /// Identify filesystem objects by file handle rather than by file
+        /// descriptor.  Events must be read with
+        /// [`Fanotify::read_fid_events`]. Since Linux 5.1.
+        FAN_REPORT_FID;
+        /// Report the file handle of the directory containing the object.
+        /// Since Linux 5.9.
+        FAN_REPORT_DIR_FID;
+        /// Report the name of the object within its directory.  Requires
+        /// `FAN_REPORT_DIR_FID`. Since Linux 5.9.
+        FAN_REPORT_NAME;
+        /// Combination of `FAN_REPORT_DIR_FID` and `FAN_REPORT_NAME`.
+        FAN_REPORT_DFID_NAME;
     }
 }
 
@@ -259,6 +274,118 @@ impl Drop for FanotifyEvent {
     }
 }
 
+libc_enum! {
+    /// Type of the file identifier carried by a [`FanotifyFidEvent`].
+    #[repr(u8)]
+    #[non_exhaustive]
+    pub enum FidInfoType {
+        /// File handle of the object, reported with `FAN_REPORT_FID`.
+        FAN_EVENT_INFO_TYPE_FID,
+        /// File handle of the parent directory and the name of the object,
+        /// reported with `FAN_REPORT_DFID_NAME`.
+        FAN_EVENT_INFO_TYPE_DFID_NAME,
+        /// File handle of the parent directory, reported with
+        /// `FAN_REPORT_DIR_FID`.
+        FAN_EVENT_INFO_TYPE_DFID,
+    }
+    impl TryFrom<u8>
+}
+
+/// An event received via [`Fanotify::read_fid_events`] from a group
+/// initialized with `FAN_REPORT_FID` or `FAN_REPORT_DIR_FID`, identifying the
+/// filesystem object by file handle.
+///
+/// An event carrying several file identifiers, e.g. both `FAN_REPORT_FID`
+/// and `FAN_REPORT_DFID_NAME` information, is returned as one
+/// `FanotifyFidEvent` per identifier.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FanotifyFidEvent {
+    mask: MaskFlags,
+    pid: i32,
+    info_type: FidInfoType,
+    fsid: libc::__kernel_fsid_t,
+    handle: Vec<u8>,
+    name: Option<OsString>,
+}
+
+impl FanotifyFidEvent {
+    /// Parse a file identifier information record of `event`, or return
+    /// `None` if `info` is another kind of record.
+    fn parse(event: &FanotifyEvent, info: &[u8]) -> Option<Self> {
+        let fid_size = size_of::<libc::fanotify_event_info_fid>();
+        let handle_header_size = size_of::<libc::file_handle>();
+        if info.len() < fid_size + handle_header_size {
+            return None;
+        }
+        // SAFETY: checked that both structures fit in `info`.
+        let (fid, handle) = unsafe {
+            (
+                ptr::read_unaligned(
+                    info.as_ptr().cast::<libc::fanotify_event_info_fid>(),
+                ),
+                ptr::read_unaligned(
+                    info.as_ptr().add(fid_size).cast::<libc::file_handle>(),
+                ),
+            )
+        };
+        let info_type = FidInfoType::try_from(fid.hdr.info_type).ok()?;
+        let handle_end = (fid_size
+            + handle_header_size
+            + handle.handle_bytes as usize)
+            .min(info.len());
+        let name = if info_type == FidInfoType::FAN_EVENT_INFO_TYPE_DFID_NAME {
+            CStr::from_bytes_until_nul(&info[handle_end..])
+                .ok()
+                .map(|name| OsStr::from_bytes(name.to_bytes()).to_owned())
+        } else {
+            None
+        };
+
+        Some(FanotifyFidEvent {
+            mask: event.mask(),
+            pid: event.pid(),
+            info_type,
+            fsid: fid.fsid,
+            handle: info[fid_size..handle_end].to_vec(),
+            name,
+        })
+    }
+
+    /// Mask flags of the events.
+    pub fn mask(&self) -> MaskFlags {
+        self.mask
+    }
+
+    /// PID of the process that caused the event. TID in case flag
+    /// `FAN_REPORT_TID` was set at group initialization.
+    pub fn pid(&self) -> i32 {
+        self.pid
+    }
+
+    /// What the file handle refers to.
+    pub fn info_type(&self) -> FidInfoType {
+        self.info_type
+    }
+
+    /// Identifier of the filesystem containing the object, as also reported
+    /// by `statfs(2)`.
+    pub fn fsid(&self) -> libc::__kernel_fsid_t {
+        self.fsid
+    }
+
+    /// The raw `struct file_handle`, including its `handle_bytes` and
+    /// `handle_type` header, as accepted by `open_by_handle_at(2)`.
+    pub fn handle(&self) -> &[u8] {
+        &self.handle
+    }
+
+    /// Name of the object within the directory identified by
+    /// [`handle`](Self::handle), for `FAN_EVENT_INFO_TYPE_DFID_NAME`.
+    pub fn name(&self) -> Option<&OsStr> {
+        self.name.as_deref()
+    }
+}
+
 /// Abstraction over the structure to be sent to allow or deny a given event.
 #[derive(Debug)]
 #[repr(transparent)]
@@ -382,6 +509,66 @@ impl Fanotify {
         Ok(events)
     }
 
+    /// Read incoming events from a fanotify group initialized with
+    /// `FAN_REPORT_FID` or `FAN_REPORT_DIR_FID`.
+    ///
+    /// Information records other than file identifiers are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`read_events`](Self::read_events).
+    pub fn read_fid_events(&self) -> Result<Vec<FanotifyFidEvent>> {
+        let metadata_size = size_of::<libc::fanotify_event_metadata>();
+        let header_size = size_of::<libc::fanotify_event_info_header>();
+        const BUFSIZ: usize = 4096;
+        let mut buffer = [0u8; BUFSIZ];
+        let mut events = Vec::new();
+        let mut offset = 0;
+
+        let nread = read(&self.fd, &mut buffer)?;
+
+        while (nread - offset) >= metadata_size {
+            // SAFETY: the kernel only writes whole records, and we checked
+            // that there is room for one.
+            let metadata = unsafe {
+                ptr::read_unaligned(
+                    buffer
+                        .as_ptr()
+                        .add(offset)
+                        .cast::<libc::fanotify_event_metadata>(),
+                )
+            };
+            let event_end = offset + metadata.event_len as usize;
+            let mut info_offset = offset + metadata.metadata_len as usize;
+            // Closes the file descriptor, if the group reports one anyway.
+            let event = FanotifyEvent(metadata);
+
+            while event_end - info_offset >= header_size {
+                let info = &buffer[info_offset..event_end];
+                // SAFETY: checked that the header fits in `info`.
+                let header = unsafe {
+                    ptr::read_unaligned(
+                        info.as_ptr().cast::<libc::fanotify_event_info_header>(),
+                    )
+                };
+                let len = header.len as usize;
+                if len == 0 || len > info.len() {
+                    break;
+                }
+                if let Some(fid_event) =
+                    FanotifyFidEvent::parse(&event, &info[..len])
+                {
+                    events.push(fid_event);
+                }
+                info_offset += len;
+            }
+
+            offset = event_end;
+        }
+
+        Ok(events)
+    }
+
     /// Write an event response on the fanotify group.
     ///
     /// Returns a Result containing either `()` on success or errno otherwise.
@@ -29,11 +29,13 @@ use crate::NixPath;
 use crate::Result;
 use cfg_if::cfg_if;
 use libc::{c_char, c_int};
+use std::collections::VecDeque;
 use std::ffi::{CStr, OsStr, OsString};
 use std::mem::{size_of, MaybeUninit};
 use std::os::unix::ffi::OsStrExt;
 use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
 use std::ptr;
+use std::time::{Duration, Instant};
 
 libc_bitflags! {
     /// Configuration options for [`inotify_add_watch`](fn.inotify_add_watch.html).
@@ -270,4 +272,119 @@ impl From<Inotify> for OwnedFd {
     fn from(value: Inotify) -> Self {
         value.fd
     }
-}
\ No newline at end of file
+}
+
+/// An event produced by [`RenameTracker`].
+#[derive(Debug)]
+pub enum TrackedEvent {
+    /// An `IN_MOVED_FROM` event and the `IN_MOVED_TO` event with the same
+    /// cookie: the object was renamed within the watched directories.
+    Renamed {
+        /// The `IN_MOVED_FROM` event, carrying the old name.
+        from: InotifyEvent,
+        /// The `IN_MOVED_TO` event, carrying the new name.
+        to: InotifyEvent,
+    },
+    /// Any other event, including an `IN_MOVED_FROM` event whose
+    /// `IN_MOVED_TO` did not arrive in time and an `IN_MOVED_TO` event
+    /// without a matching `IN_MOVED_FROM`.
+    Event(InotifyEvent),
+}
+
+/// Pairs `IN_MOVED_FROM` and `IN_MOVED_TO` events into renames.
+///
+/// The kernel reports a rename as two events sharing a cookie, which are
+/// usually but not always read together.  A `RenameTracker` holds back each
+/// `IN_MOVED_FROM` event until its `IN_MOVED_TO` arrives, or until `timeout`
+/// has elapsed, in which case the object was moved out of the watched
+/// directories.
+///
+/// # Example
+/// ```no_run
+/// # use nix::sys::inotify::{AddWatchFlags, InitFlags, Inotify, RenameTracker, TrackedEvent};
+/// # use std::time::Duration;
+/// let instance = Inotify::init(InitFlags::empty()).unwrap();
+/// instance.add_watch("test", AddWatchFlags::IN_MOVE).unwrap();
+/// let mut tracker = RenameTracker::new(Duration::from_millis(10));
+/// loop {
+///     for event in tracker.process(instance.read_events().unwrap()) {
+///         if let TrackedEvent::Renamed { from, to } = event {
+///             println!("{:?} -> {:?}", from.name, to.name);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RenameTracker {
+    timeout: Duration,
+    pending: VecDeque<(Instant, InotifyEvent)>,
+}
+
+impl RenameTracker {
+    /// Creates a tracker which waits up to `timeout` for the `IN_MOVED_TO`
+    /// event matching an `IN_MOVED_FROM` event.
+    pub fn new(timeout: Duration) -> Self {
+        RenameTracker {
+            timeout,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Processes newly read events.
+    ///
+    /// Returns the events that are ready, in order: first the
+    /// `IN_MOVED_FROM` events that have expired, then the new events, with
+    /// renames paired up.  `IN_MOVED_FROM` events are held back until their
+    /// `IN_MOVED_TO` arrives or they expire.
+    pub fn process<I>(&mut self, events: I) -> Vec<TrackedEvent>
+    where
+        I: IntoIterator<Item = InotifyEvent>,
+    {
+        let mut ready = self.flush_expired();
+        for event in events {
+            if event.mask.contains(AddWatchFlags::IN_MOVED_FROM) {
+                self.pending.push_back((Instant::now(), event));
+            } else if let Some(i) = self.find_pending(&event) {
+                let (_, from) = self.pending.remove(i).unwrap();
+                ready.push(TrackedEvent::Renamed { from, to: event });
+            } else {
+                ready.push(TrackedEvent::Event(event));
+            }
+        }
+        ready
+    }
+
+    /// Returns the held back `IN_MOVED_FROM` events that have waited longer
+    /// than the timeout for their `IN_MOVED_TO` event.
+    ///
+    /// This should be called periodically when no new events are read.
+    pub fn flush_expired(&mut self) -> Vec<TrackedEvent> {
+        let mut expired = Vec::new();
+        while let Some((since, _)) = self.pending.front() {
+            if since.elapsed() < self.timeout {
+                break;
+            }
+            let (_, event) = self.pending.pop_front().unwrap();
+            expired.push(TrackedEvent::Event(event));
+        }
+        expired
+    }
+
+    /// Returns all held back `IN_MOVED_FROM` events, regardless of the
+    /// timeout.
+    pub fn flush(&mut self) -> Vec<TrackedEvent> {
+        self.pending
+            .drain(..)
+            .map(|(_, event)| TrackedEvent::Event(event))
+            .collect()
+    }
+
+    fn find_pending(&self, event: &InotifyEvent) -> Option<usize> {
+        if !event.mask.contains(AddWatchFlags::IN_MOVED_TO) {
+            return None;
+        }
+        self.pending
+            .iter()
+            .position(|(_, from)| from.cookie == event.cookie)
+    }
+}
@@ -0,0 +1,75 @@
+//! Get and set the I/O scheduling class and priority of processes
+//!
+//! For more documentation, please read [ioprio_set(2)](https://man7.org/linux/man-pages/man2/ioprio_set.2.html).
+use crate::errno::Errno;
+use crate::Result;
+use libc::c_int;
+
+// From include/uapi/linux/ioprio.h, as are the IOPRIO_WHO_* and IOPRIO_CLASS_*
+// values below.
+const IOPRIO_CLASS_SHIFT: c_int = 13;
+// Newer kernels pack priority hints into the bits between the level and the
+// class, so only the low bits hold the level.
+const IOPRIO_LEVEL_MASK: c_int = 0x7;
+
+/// Which processes [`ioprio_set`] and [`ioprio_get`] apply to.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(i32)]
+pub enum IoprioWhich {
+    /// `who` is a process or thread ID, or 0 for the calling thread.
+    Process = 1,
+    /// `who` is a process group ID, or 0 for the calling process's group.
+    ProcessGroup = 2,
+    /// `who` is a user ID, or 0 for the calling process's real user ID.
+    User = 3,
+}
+
+/// An I/O scheduling class.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(i32)]
+pub enum IoprioClass {
+    /// No class was set.  The I/O priority is derived from the CPU nice value.
+    None = 0,
+    /// Real-time: always served first.  Levels range from 0 (highest) to 7.
+    RealTime = 1,
+    /// Best-effort, the default.  Levels range from 0 (highest) to 7.
+    BestEffort = 2,
+    /// Only served when no other process does I/O.  There are no levels.
+    Idle = 3,
+}
+
+/// Set the I/O scheduling class and priority level of processes.
+///
+/// Setting the `RealTime` class requires `CAP_SYS_ADMIN`.
+pub fn ioprio_set(
+    which: IoprioWhich,
+    who: i32,
+    class: IoprioClass,
+    level: u8,
+) -> Result<()> {
+    let ioprio = (class as c_int) << IOPRIO_CLASS_SHIFT | c_int::from(level);
+    let res = unsafe {
+        libc::syscall(libc::SYS_ioprio_set, which as c_int, who, ioprio)
+    };
+
+    Errno::result(res).map(drop)
+}
+
+/// Get the I/O scheduling class and priority level of processes.
+///
+/// If `which` selects several processes, the highest priority among them is
+/// returned.
+pub fn ioprio_get(which: IoprioWhich, who: i32) -> Result<(IoprioClass, u8)> {
+    let res =
+        unsafe { libc::syscall(libc::SYS_ioprio_get, which as c_int, who) };
+    let ioprio = Errno::result(res)? as c_int;
+
+    let class = match ioprio >> IOPRIO_CLASS_SHIFT {
+        0 => IoprioClass::None,
+        1 => IoprioClass::RealTime,
+        2 => IoprioClass::BestEffort,
+        3 => IoprioClass::Idle,
+        _ => return Err(Errno::EINVAL),
+    };
+    Ok((class, (ioprio & IOPRIO_LEVEL_MASK) as u8))
+}
@@ -381,6 +381,36 @@ pub unsafe fn mlock(addr: NonNull<c_void>, length: size_t) -> Result<()> {
     unsafe { Errno::result(libc::mlock(addr.as_ptr(), length)).map(drop) }
 }
 
+#[cfg(all(target_os = "linux", any(target_env = "gnu", target_env = "musl")))]
+libc_bitflags! {
+    /// Flags for [`mlock2`].
+    pub struct Mlock2Flags: libc::c_uint {
+        /// Lock pages only once they are faulted in, instead of populating
+        /// the whole range right away.
+        MLOCK_ONFAULT;
+    }
+}
+
+/// Locks all memory pages that contain part of the address range with `length`
+/// bytes starting at `addr`, like [`mlock`] but with `flags`.
+///
+/// # Safety
+///
+/// `addr` must meet all the requirements described in the [`mlock2(2)`] man page.
+///
+/// [`mlock2(2)`]: https://man7.org/linux/man-pages/man2/mlock2.2.html
+#[cfg(all(target_os = "linux", any(target_env = "gnu", target_env = "musl")))]
+pub unsafe fn mlock2(
+    addr: NonNull<c_void>,
+    length: size_t,
+    flags: Mlock2Flags,
+) -> Result<()> {
+    unsafe {
+        Errno::result(libc::mlock2(addr.as_ptr(), length, flags.bits()))
+            .map(drop)
+    }
+}
+
 /// Unlocks all memory pages that contain part of the address range with
 /// `length` bytes starting at `addr`.
 ///
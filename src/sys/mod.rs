@@ -10,6 +10,12 @@ feature! {
     pub mod aio;
 }
 
+#[cfg(target_os = "linux")]
+feature! {
+    #![feature = "feature"]
+    pub mod auxv;
+}
+
 feature! {
     #![feature = "event"]
 
@@ -37,6 +43,12 @@ feature! {
 #[macro_use]
 pub mod ioctl;
 
+#[cfg(target_os = "linux")]
+feature! {
+    #![feature = "process"]
+    pub mod ioprio;
+}
+
 #[cfg(any(linux_android, target_os = "freebsd"))]
 feature! {
     #![feature = "fs"]
@@ -79,6 +91,12 @@ feature! {
     pub mod quota;
 }
 
+#[cfg(any(target_os = "linux", bsd))]
+feature! {
+    #![feature = "random"]
+    pub mod random;
+}
+
 #[cfg(any(target_os = "linux", netbsdlike))]
 feature! {
     #![feature = "reboot"]
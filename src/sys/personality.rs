@@ -92,3 +92,27 @@ pub fn set(persona: Persona) -> Result<Persona> {
 
     Errno::result(res).map(Persona::from_bits_truncate)
 }
+
+/// Enable or disable address-space-layout randomization for the current
+/// process, leaving the rest of its personality unchanged.
+///
+/// This sets or clears [`Persona::ADDR_NO_RANDOMIZE`], so passing `true`
+/// disables randomization.  It only takes effect for programs subsequently
+/// loaded with `execve(2)`.
+///
+/// Returns the *previous* personality, as [`set()`] does.
+///
+/// For more information, see [personality(2)](https://man7.org/linux/man-pages/man2/personality.2.html)
+pub fn set_addr_no_randomize(no_randomize: bool) -> Result<Persona> {
+    // Work on the raw value, which also holds the execution domain that
+    // `Persona` can't represent.
+    let old = Errno::result(unsafe { libc::personality(0xFFFFFFFF) })?;
+    let new = if no_randomize {
+        old | libc::ADDR_NO_RANDOMIZE
+    } else {
+        old & !libc::ADDR_NO_RANDOMIZE
+    };
+    let res = unsafe { libc::personality(new as c_ulong) };
+
+    Errno::result(res).map(Persona::from_bits_truncate)
+}
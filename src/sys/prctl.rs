@@ -32,6 +32,154 @@ libc_enum! {
     impl TryFrom<i32>
 }
 
+/// A Linux capability, as used by [`capbset_read`] and [`capbset_drop`].
+///
+/// For more documentation, please read [capabilities(7)](https://man7.org/linux/man-pages/man7/capabilities.7.html).
+// The values come from include/uapi/linux/capability.h.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(i32)]
+#[non_exhaustive]
+#[allow(non_camel_case_types)]
+pub enum Capability {
+    /// Make arbitrary changes to file UIDs and GIDs.
+    CAP_CHOWN = 0,
+    /// Bypass file read, write, and execute permission checks.
+    CAP_DAC_OVERRIDE = 1,
+    /// Bypass file read permission checks and directory read and execute
+    /// permission checks.
+    CAP_DAC_READ_SEARCH = 2,
+    /// Bypass permission checks on operations that require the file's UID to
+    /// match the process's.
+    CAP_FOWNER = 3,
+    /// Don't clear set-user-ID and set-group-ID bits when a file is modified.
+    CAP_FSETID = 4,
+    /// Bypass permission checks for sending signals.
+    CAP_KILL = 5,
+    /// Make arbitrary manipulations of process GIDs and supplementary GIDs.
+    CAP_SETGID = 6,
+    /// Make arbitrary manipulations of process UIDs.
+    CAP_SETUID = 7,
+    /// Add capabilities to the inheritable set, and drop capabilities from the
+    /// bounding set.
+    CAP_SETPCAP = 8,
+    /// Set the immutable and append-only file flags.
+    CAP_LINUX_IMMUTABLE = 9,
+    /// Bind a socket to a privileged port.
+    CAP_NET_BIND_SERVICE = 10,
+    /// Make socket broadcasts and listen to multicasts (unused).
+    CAP_NET_BROADCAST = 11,
+    /// Perform network-related administration operations.
+    CAP_NET_ADMIN = 12,
+    /// Use raw and packet sockets.
+    CAP_NET_RAW = 13,
+    /// Lock memory.
+    CAP_IPC_LOCK = 14,
+    /// Bypass permission checks for operations on System V IPC objects.
+    CAP_IPC_OWNER = 15,
+    /// Load and unload kernel modules.
+    CAP_SYS_MODULE = 16,
+    /// Perform I/O port operations.
+    CAP_SYS_RAWIO = 17,
+    /// Use `chroot`.
+    CAP_SYS_CHROOT = 18,
+    /// Trace arbitrary processes.
+    CAP_SYS_PTRACE = 19,
+    /// Use `acct`.
+    CAP_SYS_PACCT = 20,
+    /// Perform a range of system administration operations.
+    CAP_SYS_ADMIN = 21,
+    /// Use `reboot` and `kexec_load`.
+    CAP_SYS_BOOT = 22,
+    /// Raise process nice values and change scheduling policies.
+    CAP_SYS_NICE = 23,
+    /// Override resource limits.
+    CAP_SYS_RESOURCE = 24,
+    /// Set the system clock.
+    CAP_SYS_TIME = 25,
+    /// Use `vhangup` and privileged terminal `ioctl`s.
+    CAP_SYS_TTY_CONFIG = 26,
+    /// Create special files using `mknod`.
+    CAP_MKNOD = 27,
+    /// Establish leases on arbitrary files.
+    CAP_LEASE = 28,
+    /// Write records to the kernel auditing log.
+    CAP_AUDIT_WRITE = 29,
+    /// Configure kernel auditing.
+    CAP_AUDIT_CONTROL = 30,
+    /// Set arbitrary capabilities on a file.
+    CAP_SETFCAP = 31,
+    /// Override Mandatory Access Control.
+    CAP_MAC_OVERRIDE = 32,
+    /// Configure Mandatory Access Control.
+    CAP_MAC_ADMIN = 33,
+    /// Perform privileged `syslog` operations.
+    CAP_SYSLOG = 34,
+    /// Trigger something that will wake up the system.
+    CAP_WAKE_ALARM = 35,
+    /// Employ features that can block system suspend.
+    CAP_BLOCK_SUSPEND = 36,
+    /// Read the audit log via a multicast netlink socket.
+    CAP_AUDIT_READ = 37,
+    /// Employ performance monitoring mechanisms.
+    CAP_PERFMON = 38,
+    /// Employ privileged BPF operations.
+    CAP_BPF = 39,
+    /// Perform checkpoint and restore operations.
+    CAP_CHECKPOINT_RESTORE = 40,
+}
+
+libc_enum! {
+    /// A field of the process's memory map, as set by [`set_mm`].
+    #[repr(i32)]
+    #[non_exhaustive]
+    #[allow(non_camel_case_types)]
+    pub enum PrctlMmField {
+        /// The address above which program text can run.
+        PR_SET_MM_START_CODE,
+        /// The address below which program text can run.
+        PR_SET_MM_END_CODE,
+        /// The address above which initialized and uninitialized data are
+        /// placed.
+        PR_SET_MM_START_DATA,
+        /// The address below which initialized and uninitialized data are
+        /// placed.
+        PR_SET_MM_END_DATA,
+        /// The start address of the stack.
+        PR_SET_MM_START_STACK,
+        /// The address above which the program heap can be expanded with
+        /// `brk`.
+        PR_SET_MM_START_BRK,
+        /// The current `brk` value.
+        PR_SET_MM_BRK,
+        /// The address above which the program command line is placed.
+        PR_SET_MM_ARG_START,
+        /// The address below which the program command line is placed.
+        PR_SET_MM_ARG_END,
+        /// The address above which the program environment is placed.
+        PR_SET_MM_ENV_START,
+        /// The address below which the program environment is placed.
+        PR_SET_MM_ENV_END,
+    }
+    impl TryFrom<i32>
+}
+
+/// The dumpable attribute of a process, as used by [`set_dumpable`] and
+/// [`get_dumpable`].  It determines whether the process produces core dumps
+/// and can be attached to with `ptrace`.
+// The values are SUID_DUMP_DISABLE, SUID_DUMP_USER and SUID_DUMP_ROOT from the
+// kernel-internal include/linux/sched/coredump.h, as documented in prctl(2).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(i32)]
+pub enum Dumpable {
+    /// The process is not dumpable.
+    NotDumpable = 0,
+    /// The process is dumpable.
+    UserDumpable = 1,
+    /// Core dumps are only readable by root, as set by the `suid_dumpable`
+    /// sysctl for set-user-ID programs.
+    RootDumpable = 2,
+}
+
 fn prctl_set_bool(option: c_int, status: bool) -> Result<()> {
     let res = unsafe { libc::prctl(option, status as c_ulong, 0, 0, 0) };
     Errno::result(res).map(drop)
@@ -43,6 +191,9 @@ fn prctl_get_bool(option: c_int) -> Result<bool> {
 }
 
 /// Set the "child subreaper" attribute for this process
+///
+/// When a process is orphaned, it is reparented to its nearest ancestor that
+/// is a subreaper instead of to init, so that ancestor can `wait` for it.
 pub fn set_child_subreaper(attribute: bool) -> Result<()> {
     prctl_set_bool(libc::PR_SET_CHILD_SUBREAPER, attribute)
 }
@@ -60,13 +211,27 @@ pub fn get_child_subreaper() -> Result<bool> {
 }
 
 /// Set the dumpable attribute which determines if core dumps are created for this process.
-pub fn set_dumpable(attribute: bool) -> Result<()> {
-    prctl_set_bool(libc::PR_SET_DUMPABLE, attribute)
+///
+/// Only [`Dumpable::NotDumpable`] and [`Dumpable::UserDumpable`] may be set;
+/// [`Dumpable::RootDumpable`] fails with `EINVAL`.
+pub fn set_dumpable(mode: Dumpable) -> Result<()> {
+    let res = unsafe {
+        libc::prctl(libc::PR_SET_DUMPABLE, mode as c_ulong, 0, 0, 0)
+    };
+
+    Errno::result(res).map(drop)
 }
 
 /// Get the dumpable attribute for this process.
-pub fn get_dumpable() -> Result<bool> {
-    prctl_get_bool(libc::PR_GET_DUMPABLE)
+pub fn get_dumpable() -> Result<Dumpable> {
+    let res = unsafe { libc::prctl(libc::PR_GET_DUMPABLE, 0, 0, 0, 0) };
+
+    match Errno::result(res)? {
+        0 => Ok(Dumpable::NotDumpable),
+        1 => Ok(Dumpable::UserDumpable),
+        2 => Ok(Dumpable::RootDumpable),
+        _ => Err(Errno::EINVAL),
+    }
 }
 
 /// Set the "keep capabilities" attribute for this process. This causes the thread to retain
@@ -80,6 +245,25 @@ pub fn get_keepcaps() -> Result<bool> {
     prctl_get_bool(libc::PR_GET_KEEPCAPS)
 }
 
+/// Check whether `cap` is in the calling thread's capability bounding set.
+pub fn capbset_read(cap: Capability) -> Result<bool> {
+    let res = unsafe {
+        libc::prctl(libc::PR_CAPBSET_READ, cap as c_ulong, 0, 0, 0)
+    };
+
+    Errno::result(res).map(|res| res != 0)
+}
+
+/// Drop `cap` from the calling thread's capability bounding set.  This
+/// requires `CAP_SETPCAP`, and can't be undone.
+pub fn capbset_drop(cap: Capability) -> Result<()> {
+    let res = unsafe {
+        libc::prctl(libc::PR_CAPBSET_DROP, cap as c_ulong, 0, 0, 0)
+    };
+
+    Errno::result(res).map(drop)
+}
+
 /// Clear the thread memory corruption kill policy and use the system-wide default
 pub fn clear_mce_kill() -> Result<()> {
     let res = unsafe {
@@ -153,9 +337,9 @@ pub fn set_name(name: &CStr) -> Result<()> {
 /// Return the name of the calling thread
 pub fn get_name() -> Result<CString> {
     // Size of buffer determined by linux/sched.h TASK_COMM_LEN
-    let buf = [0u8; 16];
+    let mut buf = [0u8; 16];
 
-    let res = unsafe { libc::prctl(libc::PR_GET_NAME, &buf, 0, 0, 0) };
+    let res = unsafe { libc::prctl(libc::PR_GET_NAME, buf.as_mut_ptr(), 0, 0, 0) };
 
     Errno::result(res).and_then(|_| {
         CStr::from_bytes_until_nul(&buf)
@@ -166,17 +350,19 @@ pub fn get_name() -> Result<CString> {
 
 /// Sets the timer slack value for the calling thread. Timer slack is used by the kernel to group
 /// timer expirations and make them the supplied amount of nanoseconds late.
-pub fn set_timerslack(ns: c_ulong) -> Result<()> {
-    let res = unsafe { libc::prctl(libc::PR_SET_TIMERSLACK, ns, 0, 0, 0) };
+pub fn set_timerslack(ns: u64) -> Result<()> {
+    let res = unsafe {
+        libc::prctl(libc::PR_SET_TIMERSLACK, ns as c_ulong, 0, 0, 0)
+    };
 
     Errno::result(res).map(drop)
 }
 
 /// Get the timerslack for the calling thread.
-pub fn get_timerslack() -> Result<i32> {
+pub fn get_timerslack() -> Result<u64> {
     let res = unsafe { libc::prctl(libc::PR_GET_TIMERSLACK, 0, 0, 0, 0) };
 
-    Errno::result(res)
+    Errno::result(res).map(|res| res as u64)
 }
 
 /// Disable all performance counters attached to the calling process.
@@ -195,6 +381,22 @@ pub fn task_perf_events_enable() -> Result<()> {
     Errno::result(res).map(drop)
 }
 
+/// Change a field of the calling process's memory map, as used for
+/// checkpoint/restore.  This requires `CAP_SYS_RESOURCE`.
+pub fn set_mm(field: PrctlMmField, value: u64) -> Result<()> {
+    let res = unsafe {
+        libc::prctl(
+            libc::PR_SET_MM,
+            field as c_ulong,
+            value as c_ulong,
+            0,
+            0,
+        )
+    };
+
+    Errno::result(res).map(drop)
+}
+
 /// Set the calling threads "no new privs" attribute. Once set this option can not be unset.
 pub fn set_no_new_privs() -> Result<()> {
     prctl_set_bool(libc::PR_SET_NO_NEW_PRIVS, true) // Cannot be unset
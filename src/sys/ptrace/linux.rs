@@ -226,7 +226,8 @@ libc_enum! {
 /// # Safety
 ///
 /// This trait is marked unsafe, since implementation of the trait must match
-/// ptrace's request `VALUE` and return data type `Regs`.
+/// ptrace's request `VALUE` and return data type `Regs`.  `Regs` must also be
+/// valid when zeroed, as the kernel may fill in only a prefix of it.
 pub unsafe trait RegisterSet {
     /// Corresponding type of registers in the kernel.
     const VALUE: RegisterSetValue;
@@ -388,7 +389,10 @@ pub fn getregs(pid: Pid) -> Result<user_regs_struct> {
 ))]
 pub fn getregset<S: RegisterSet>(pid: Pid) -> Result<S::Regs> {
     let request = Request::PTRACE_GETREGSET;
-    let mut data = mem::MaybeUninit::<S::Regs>::uninit();
+    // The kernel shrinks `iov_len` to the size of the register set when that
+    // is smaller than the buffer, leaving the tail untouched, so start from
+    // zeroed memory rather than uninitialized memory.
+    let mut data = mem::MaybeUninit::<S::Regs>::zeroed();
     let mut iov = libc::iovec {
         iov_base: data.as_mut_ptr().cast(),
         iov_len: mem::size_of::<S::Regs>(),
@@ -804,3 +808,88 @@ pub fn write_user(pid: Pid, offset: AddressType, data: c_long) -> Result<()> {
             .map(drop)
     }
 }
+
+/// A process attached with [`attach`], which is detached again on drop.
+///
+/// Most ptrace requests require the tracee to be stopped.  After attaching,
+/// the process is in the stop caused by the attach, and it should be stopped
+/// again when dropped, or detaching fails.
+///
+/// # Example
+///
+/// ```no_run
+/// # use nix::sys::ptrace::TracedProcess;
+/// # use nix::unistd::Pid;
+/// # let pid = Pid::from_raw(1234);
+/// let traced = TracedProcess::attach(pid).unwrap();
+/// let word = traced.read(std::ptr::null_mut()).unwrap();
+/// // Let the process continue
+/// drop(traced);
+/// ```
+#[derive(Debug)]
+pub struct TracedProcess {
+    pid: Pid,
+}
+
+impl TracedProcess {
+    /// Attach to the process `pid`, and wait until it is stopped.
+    ///
+    /// Other signals arriving before the stop are delivered to the process.
+    pub fn attach(pid: Pid) -> Result<Self> {
+        use crate::sys::wait::{waitpid, WaitPidFlag, WaitStatus};
+
+        attach(pid)?;
+        let traced = TracedProcess { pid };
+        loop {
+            match waitpid(pid, Some(WaitPidFlag::__WALL))? {
+                WaitStatus::Stopped(_, Signal::SIGSTOP) => return Ok(traced),
+                WaitStatus::Stopped(_, sig) => cont(pid, sig)?,
+                WaitStatus::Exited(..) | WaitStatus::Signaled(..) => {
+                    // There is nothing left to detach from.
+                    mem::forget(traced);
+                    return Err(Errno::ESRCH);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// The process ID of the tracee.
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Get the tracee's registers, as with [`getregs`].
+    #[cfg(all(
+        target_os = "linux",
+        any(
+            all(
+                any(target_arch = "x86_64", target_arch = "aarch64"),
+                any(target_env = "gnu", target_env = "musl")
+            ),
+            all(target_arch = "x86", target_env = "gnu"),
+            all(target_arch = "riscv64", target_env = "gnu"),
+        ),
+    ))]
+    pub fn getregs(&self) -> Result<user_regs_struct> {
+        getregs(self.pid)
+    }
+
+    /// Read a word from the tracee's memory, as with [`read`].
+    pub fn read(&self, addr: AddressType) -> Result<c_long> {
+        read(self.pid, addr)
+    }
+
+    /// Detach from the tracee, optionally delivering the signal `sig`.
+    pub fn detach<T: Into<Option<Signal>>>(self, sig: T) -> Result<()> {
+        let pid = self.pid;
+        mem::forget(self);
+        detach(pid, sig)
+    }
+}
+
+impl Drop for TracedProcess {
+    fn drop(&mut self) {
+        let _ = detach(self.pid, None);
+    }
+}
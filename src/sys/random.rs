@@ -0,0 +1,60 @@
+//! Obtain random bytes from the operating system
+//!
+//! [Further reading](https://man7.org/linux/man-pages/man2/getrandom.2.html)
+#[cfg(target_os = "linux")]
+use crate::errno::Errno;
+#[cfg(target_os = "linux")]
+use crate::Result;
+
+#[cfg(target_os = "linux")]
+libc_bitflags! {
+    /// Flags for [`getrandom`].
+    pub struct GetRandomFlags: libc::c_uint {
+        /// Fail with `EAGAIN` instead of blocking when no random bytes are
+        /// available.
+        GRND_NONBLOCK;
+        /// Draw from the blocking `/dev/random` source instead of
+        /// `/dev/urandom`.
+        GRND_RANDOM;
+    }
+}
+
+/// Fill `buf` with random bytes.
+///
+/// Returns the number of bytes read, which may be less than the length of
+/// `buf` if the call was interrupted by a signal, or if more than 256 bytes
+/// were requested with `GRND_RANDOM`.  It is up to the caller to retry.
+///
+/// # Example
+/// ```
+/// # use nix::sys::random::{getrandom, GetRandomFlags};
+/// let mut key = [0u8; 16];
+/// let n = getrandom(&mut key, GetRandomFlags::empty()).unwrap();
+/// assert_eq!(n, key.len());
+/// ```
+#[cfg(target_os = "linux")]
+pub fn getrandom(buf: &mut [u8], flags: GetRandomFlags) -> Result<usize> {
+    let res = unsafe {
+        libc::getrandom(buf.as_mut_ptr().cast(), buf.len(), flags.bits())
+    };
+    Errno::result(res).map(|r| r as usize)
+}
+
+/// Fill `buf` with random bytes from the kernel-seeded arc4random generator.
+///
+/// Unlike [`getrandom`](https://man7.org/linux/man-pages/man2/getrandom.2.html),
+/// this never fails and never blocks.
+///
+/// See also [arc4random(3)](https://man.freebsd.org/cgi/man.cgi?query=arc4random&sektion=3)
+#[cfg(bsd)]
+pub fn arc4random_buf(buf: &mut [u8]) {
+    unsafe { libc::arc4random_buf(buf.as_mut_ptr().cast(), buf.len()) }
+}
+
+/// Return a random 32-bit value from the arc4random generator.
+///
+/// See also [arc4random(3)](https://man.freebsd.org/cgi/man.cgi?query=arc4random&sektion=3)
+#[cfg(bsd)]
+pub fn arc4random() -> u32 {
+    unsafe { libc::arc4random() }
+}
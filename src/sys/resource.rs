@@ -246,6 +246,40 @@ pub fn setrlimit(
     Errno::result(res).map(drop)
 }
 
+/// Get the soft and hard limits on the number of open file descriptors.
+///
+/// A shorthand for [`getrlimit`] with [`Resource::RLIMIT_NOFILE`].
+pub fn fd_limit() -> Result<(rlim_t, rlim_t)> {
+    getrlimit(Resource::RLIMIT_NOFILE)
+}
+
+/// Raise the soft limit on the number of open file descriptors as far as
+/// possible, and return the new soft limit.
+///
+/// Usually that is the hard limit.  But on Apple platforms `setrlimit` refuses
+/// soft limits above `OPEN_MAX`, so the soft limit is capped to that.
+///
+/// # Examples
+///
+/// ```
+/// # use nix::sys::resource::{fd_limit, raise_fd_limit_to_max};
+/// let soft_limit = raise_fd_limit_to_max().unwrap();
+/// assert_eq!(fd_limit().unwrap().0, soft_limit);
+/// ```
+pub fn raise_fd_limit_to_max() -> Result<rlim_t> {
+    let (_, hard_limit) = fd_limit()?;
+    #[cfg(apple_targets)]
+    let soft_limit = {
+        // OPEN_MAX from <sys/syslimits.h>, which libc does not bind.
+        const OPEN_MAX: rlim_t = 10240;
+        hard_limit.min(OPEN_MAX)
+    };
+    #[cfg(not(apple_targets))]
+    let soft_limit = hard_limit;
+    setrlimit(Resource::RLIMIT_NOFILE, soft_limit, hard_limit)?;
+    Ok(soft_limit)
+}
+
 libc_enum! {
     /// Whose resource usage should be returned by [`getrusage`].
     #[repr(i32)]
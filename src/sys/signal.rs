@@ -118,11 +118,23 @@ libc_enum! {
     impl TryFrom<i32>
 }
 
+/// Parses a signal from its name or number.
+///
+/// The name is matched case-insensitively, with or without the `SIG` prefix,
+/// so `"SIGTERM"`, `"term"` and `"15"` all yield [`Signal::SIGTERM`].
+/// Unknown names and numbers fail with `EINVAL`.
 #[cfg(feature = "signal")]
 impl FromStr for Signal {
     type Err = Error;
     fn from_str(s: &str) -> Result<Signal> {
-        Ok(match s {
+        if let Ok(signum) = s.parse::<i32>() {
+            return Signal::try_from(signum);
+        }
+        let mut name = s.to_ascii_uppercase();
+        if !name.starts_with("SIG") {
+            name.insert_str(0, "SIG");
+        }
+        Ok(match name.as_str() {
             "SIGHUP" => Signal::SIGHUP,
             "SIGINT" => Signal::SIGINT,
             "SIGQUIT" => Signal::SIGQUIT,
@@ -414,6 +426,33 @@ impl Signal {
     }
 }
 
+#[cfg(linux_android)]
+#[allow(non_snake_case)]
+impl Signal {
+    /// The lowest real-time signal number available to applications.
+    ///
+    /// This is a function rather than a constant because the C library
+    /// reserves some real-time signals for its own use, so the value is only
+    /// known at run time.  Real-time signals can't be represented by
+    /// [`Signal`], so this is a raw signal number.
+    pub fn SIGRTMIN() -> libc::c_int {
+        libc::SIGRTMIN()
+    }
+
+    /// The highest real-time signal number.
+    ///
+    /// Like [`Signal::SIGRTMIN`], this is a raw signal number.
+    pub fn SIGRTMAX() -> libc::c_int {
+        libc::SIGRTMAX()
+    }
+
+    /// Iterate through the raw numbers of all real-time signals available to
+    /// applications, from [`Signal::SIGRTMIN`] to [`Signal::SIGRTMAX`].
+    pub fn realtime_signals() -> impl Iterator<Item = libc::c_int> {
+        Self::SIGRTMIN()..=Self::SIGRTMAX()
+    }
+}
+
 /// Alias for [`SIGABRT`]
 pub const SIGIOT : Signal = SIGABRT;
 /// Alias for [`SIGIO`]
@@ -551,6 +590,42 @@ impl SigSet {
         self.into_iter()
     }
 
+    /// Returns a set of the signals contained in either `self` or `other`.
+    ///
+    /// Unlike [`iter`](Self::iter), this also covers real-time signals.
+    pub fn union(&self, other: &SigSet) -> SigSet {
+        let mut union = *self;
+        for signum in other.raw_members() {
+            unsafe { libc::sigaddset(&mut union.sigset, signum) };
+        }
+        union
+    }
+
+    /// Returns a set of the signals contained in both `self` and `other`.
+    ///
+    /// Unlike [`iter`](Self::iter), this also covers real-time signals.
+    pub fn intersection(&self, other: &SigSet) -> SigSet {
+        let mut intersection = *self;
+        for signum in self.raw_members() {
+            if !other.contains_raw(signum) {
+                unsafe { libc::sigdelset(&mut intersection.sigset, signum) };
+            }
+        }
+        intersection
+    }
+
+    /// Returns the numbers of all the signals in this set, including those,
+    /// like real-time signals, that `Signal` can't represent.
+    fn raw_members(&self) -> impl Iterator<Item = libc::c_int> + '_ {
+        // A signal number can't exceed the number of bits in a sigset_t.
+        let max = (mem::size_of::<libc::sigset_t>() * 8) as libc::c_int;
+        (1..=max).filter(move |&signum| self.contains_raw(signum))
+    }
+
+    fn contains_raw(&self, signum: libc::c_int) -> bool {
+        unsafe { libc::sigismember(&self.sigset, signum) == 1 }
+    }
+
     /// Gets the currently blocked (masked) set of signals for the calling thread.
     pub fn thread_get_mask() -> Result<SigSet> {
         let mut oldmask = mem::MaybeUninit::uninit();
@@ -580,6 +655,22 @@ impl SigSet {
         Ok(unsafe{ SigSet{sigset: oldmask.assume_init()}})
     }
 
+    /// Returns the set of signals that were raised while blocked, and are
+    /// waiting to be delivered to the calling thread or process.
+    ///
+    /// The set is the union of the signals pending for the calling thread,
+    /// e.g. sent with [`raise`] or `pthread_kill`, and those pending for the
+    /// whole process.
+    ///
+    /// For more information see the
+    /// [`sigpending(2)`](https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigpending.html).
+    #[doc(alias("sigpending"))]
+    pub fn pending() -> Result<SigSet> {
+        let mut set = mem::MaybeUninit::uninit();
+        let res = unsafe { libc::sigpending(set.as_mut_ptr()) };
+        Errno::result(res).map(|_| unsafe { SigSet{sigset: set.assume_init()} })
+    }
+
     /// Suspends execution of the calling thread until one of the signals in the
     /// signal mask becomes pending, and returns the accepted signal.
     #[cfg(not(target_os = "redox"))] // RedoxFS does not yet support sigwait
@@ -594,6 +685,43 @@ impl SigSet {
         })
     }
 
+    /// Like [`wait`](Self::wait), but gives up after `timeout`.
+    ///
+    /// Returns `Ok(None)` if none of the signals became pending in time.
+    ///
+    /// For more information see the
+    /// [`sigtimedwait(2)`](https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigtimedwait.html).
+    #[cfg(any(
+        linux_android,
+        freebsdlike,
+        target_os = "netbsd",
+        target_os = "haiku",
+        target_os = "hurd",
+        target_os = "aix",
+        target_os = "fuchsia"
+    ))]
+    #[doc(alias("sigtimedwait"))]
+    pub fn wait_timeout(
+        &self,
+        timeout: crate::sys::time::TimeSpec,
+    ) -> Result<Option<Signal>> {
+        use std::convert::TryFrom;
+
+        let res = unsafe {
+            libc::sigtimedwait(
+                &self.sigset as *const libc::sigset_t,
+                ptr::null_mut(),
+                timeout.as_ref(),
+            )
+        };
+
+        match Errno::result(res) {
+            Ok(signum) => Signal::try_from(signum).map(Some),
+            Err(Errno::EAGAIN) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Wait for a signal
     ///
     /// # Return value
@@ -669,7 +797,7 @@ impl BitOr for SigSet {
     type Output = Self;
 
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.iter().chain(rhs.iter()).collect()
+        self.union(&rhs)
     }
 }
 
@@ -918,6 +1046,44 @@ pub unsafe fn sigaction(signal: Signal, sigaction: &SigAction) -> Result<SigActi
     Errno::result(res).map(|_| SigAction { sigaction: unsafe { oldact.assume_init() } })
 }
 
+/// Guard returned by [`sigaction_scoped`].
+///
+/// Restores the previous action for its signal when dropped.
+#[derive(Debug)]
+#[must_use = "the previous action is restored as soon as the guard is dropped"]
+pub struct SigActionGuard {
+    signal: Signal,
+    old: SigAction,
+}
+
+impl SigActionGuard {
+    /// Returns the action that will be restored when the guard is dropped.
+    pub fn old_action(&self) -> &SigAction {
+        &self.old
+    }
+}
+
+impl Drop for SigActionGuard {
+    fn drop(&mut self) {
+        // The old action was accepted by the kernel once already, so
+        // reinstalling it can't fail.
+        let _ = unsafe { sigaction(self.signal, &self.old) };
+    }
+}
+
+/// Like [`sigaction`], but restores the previous action for `signal` when
+/// the returned guard is dropped.
+///
+/// # Safety
+///
+/// The same requirements as for [`sigaction`] apply.  In addition, the guard
+/// reinstalls the old action without regard to any other changes made to
+/// `signal`'s action while it was alive.
+pub unsafe fn sigaction_scoped(signal: Signal, sigaction: &SigAction) -> Result<SigActionGuard> {
+    let old = unsafe { self::sigaction(signal, sigaction) }?;
+    Ok(SigActionGuard { signal, old })
+}
+
 /// Signal management (see [signal(3p)](https://pubs.opengroup.org/onlinepubs/9699919799/functions/signal.html))
 ///
 /// Installs `handler` for the given `signal`, returning the previous signal
@@ -107,6 +107,8 @@ impl SignalFd {
         Ok(SignalFd(fd))
     }
 
+    /// Replace the set of signals watched by this signalfd, without
+    /// creating a new file descriptor.
     pub fn set_mask(&self, mask: &SigSet) -> Result<()> {
         self.update(mask, SfdFlags::empty())
     }
@@ -14,8 +14,12 @@ use super::sa_family_t;
 use crate::errno::Errno;
 #[cfg(linux_android)]
 use crate::sys::socket::addr::alg::AlgAddr;
+#[cfg(target_os = "linux")]
+use crate::sys::socket::addr::can::CanAddr;
 #[cfg(linux_android)]
 use crate::sys::socket::addr::netlink::NetlinkAddr;
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+use crate::sys::socket::addr::xdp::XdpAddr;
 #[cfg(all(feature = "ioctl", apple_targets))]
 use crate::sys::socket::addr::sys_control::SysControlAddr;
 use crate::{NixPath, Result};
@@ -201,6 +205,9 @@ pub enum AddressFamily {
     /// VMWare VSockets protocol for hypervisor-guest interaction.
     #[cfg(any(linux_android, apple_targets))]
     Vsock = libc::AF_VSOCK,
+    /// Express Data Path sockets (see [`XdpAddr`](crate::sys::socket::XdpAddr))
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    Xdp = libc::AF_XDP,
     /// ARPANet IMP addresses
     #[cfg(bsd)]
     ImpLink = libc::AF_IMPLINK,
@@ -261,6 +268,8 @@ impl AddressFamily {
             libc::AF_INET6 => Some(AddressFamily::Inet6),
             #[cfg(linux_android)]
             libc::AF_NETLINK => Some(AddressFamily::Netlink),
+            #[cfg(linux_android)]
+            libc::AF_CAN => Some(AddressFamily::Can),
             #[cfg(apple_targets)]
             libc::AF_SYSTEM => Some(AddressFamily::System),
             #[cfg(not(any(linux_android, target_os = "redox")))]
@@ -271,6 +280,8 @@ impl AddressFamily {
             libc::AF_LINK => Some(AddressFamily::Link),
             #[cfg(any(linux_android, apple_targets))]
             libc::AF_VSOCK => Some(AddressFamily::Vsock),
+            #[cfg(all(target_os = "linux", target_env = "gnu"))]
+            libc::AF_XDP => Some(AddressFamily::Xdp),
             _ => None,
         }
     }
@@ -1122,6 +1133,8 @@ impl std::str::FromStr for SockaddrIn6 {
 pub union SockaddrStorage {
     #[cfg(linux_android)]
     alg: AlgAddr,
+    #[cfg(target_os = "linux")]
+    can: CanAddr,
     #[cfg(all(
         feature = "net",
         not(any(target_os = "hurd", target_os = "redox"))
@@ -1141,6 +1154,8 @@ pub union SockaddrStorage {
     su: UnixAddr,
     #[cfg(any(linux_android, apple_targets))]
     vsock: VsockAddr,
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    xdp: XdpAddr,
 }
 impl private::SockaddrLikePriv for SockaddrStorage {}
 impl SockaddrLike for SockaddrStorage {
@@ -1189,6 +1204,10 @@ impl SockaddrLike for SockaddrStorage {
                 libc::AF_ALG => unsafe {
                     AlgAddr::from_raw(addr, l).map(|alg| Self { alg })
                 },
+                #[cfg(target_os = "linux")]
+                libc::AF_CAN => unsafe {
+                    CanAddr::from_raw(addr, l).map(|can| Self { can })
+                },
                 #[cfg(feature = "net")]
                 libc::AF_INET => unsafe {
                     SockaddrIn::from_raw(addr, l).map(|sin| Self { sin })
@@ -1219,6 +1238,10 @@ impl SockaddrLike for SockaddrStorage {
                 libc::AF_VSOCK => unsafe {
                     VsockAddr::from_raw(addr, l).map(|vsock| Self { vsock })
                 },
+                #[cfg(all(target_os = "linux", target_env = "gnu"))]
+                libc::AF_XDP => unsafe {
+                    XdpAddr::from_raw(addr, l).map(|xdp| Self { xdp })
+                },
                 _ => None,
             }
         }
@@ -1342,6 +1365,10 @@ impl SockaddrStorage {
     accessors! {as_alg_addr, as_alg_addr_mut, AlgAddr,
     AddressFamily::Alg, libc::sockaddr_alg, alg}
 
+    #[cfg(target_os = "linux")]
+    accessors! {as_can_addr, as_can_addr_mut, CanAddr,
+    AddressFamily::Can, libc::sockaddr_can, can}
+
     #[cfg(any(linux_android, target_os = "fuchsia"))]
     #[cfg(feature = "net")]
     accessors! {
@@ -1376,6 +1403,10 @@ impl SockaddrStorage {
     #[cfg(any(linux_android, apple_targets))]
     accessors! {as_vsock_addr, as_vsock_addr_mut, VsockAddr,
     AddressFamily::Vsock, libc::sockaddr_vm, vsock}
+
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    accessors! {as_xdp_addr, as_xdp_addr_mut, XdpAddr,
+    AddressFamily::Xdp, libc::sockaddr_xdp, xdp}
 }
 
 impl fmt::Debug for SockaddrStorage {
@@ -1394,6 +1425,8 @@ impl fmt::Display for SockaddrStorage {
             match self.ss.ss_family as i32 {
                 #[cfg(linux_android)]
                 libc::AF_ALG => self.alg.fmt(f),
+                #[cfg(target_os = "linux")]
+                libc::AF_CAN => self.can.fmt(f),
                 #[cfg(feature = "net")]
                 libc::AF_INET => self.sin.fmt(f),
                 #[cfg(feature = "net")]
@@ -1412,6 +1445,8 @@ impl fmt::Display for SockaddrStorage {
                 libc::AF_UNIX => self.su.fmt(f),
                 #[cfg(any(linux_android, apple_targets))]
                 libc::AF_VSOCK => self.vsock.fmt(f),
+                #[cfg(all(target_os = "linux", target_env = "gnu"))]
+                libc::AF_XDP => self.xdp.fmt(f),
                 _ => "<Address family unspecified>".fmt(f),
             }
         }
@@ -1456,6 +1491,8 @@ impl Hash for SockaddrStorage {
             match self.ss.ss_family as i32 {
                 #[cfg(linux_android)]
                 libc::AF_ALG => self.alg.hash(s),
+                #[cfg(target_os = "linux")]
+                libc::AF_CAN => self.can.hash(s),
                 #[cfg(feature = "net")]
                 libc::AF_INET => self.sin.hash(s),
                 #[cfg(feature = "net")]
@@ -1474,6 +1511,8 @@ impl Hash for SockaddrStorage {
                 libc::AF_UNIX => self.su.hash(s),
                 #[cfg(any(linux_android, apple_targets))]
                 libc::AF_VSOCK => self.vsock.hash(s),
+                #[cfg(all(target_os = "linux", target_env = "gnu"))]
+                libc::AF_XDP => self.xdp.hash(s),
                 _ => self.ss.hash(s),
             }
         }
@@ -1486,6 +1525,8 @@ impl PartialEq for SockaddrStorage {
             match (self.ss.ss_family as i32, other.ss.ss_family as i32) {
                 #[cfg(linux_android)]
                 (libc::AF_ALG, libc::AF_ALG) => self.alg == other.alg,
+                #[cfg(target_os = "linux")]
+                (libc::AF_CAN, libc::AF_CAN) => self.can == other.can,
                 #[cfg(feature = "net")]
                 (libc::AF_INET, libc::AF_INET) => self.sin == other.sin,
                 #[cfg(feature = "net")]
@@ -1504,6 +1545,8 @@ impl PartialEq for SockaddrStorage {
                 (libc::AF_UNIX, libc::AF_UNIX) => self.su == other.su,
                 #[cfg(any(linux_android, apple_targets))]
                 (libc::AF_VSOCK, libc::AF_VSOCK) => self.vsock == other.vsock,
+                #[cfg(all(target_os = "linux", target_env = "gnu"))]
+                (libc::AF_XDP, libc::AF_XDP) => self.xdp == other.xdp,
                 _ => false,
             }
         }
@@ -1599,6 +1642,250 @@ pub mod netlink {
     }
 }
 
+#[cfg(target_os = "linux")]
+pub mod can {
+    use super::*;
+    use crate::sys::socket::addr::AddressFamily;
+    use libc::{sa_family_t, sockaddr_can};
+    use std::hash::{Hash, Hasher};
+    use std::{fmt, mem};
+
+    /// Socket address for Controller Area Network (SocketCAN) sockets.
+    ///
+    /// Raw and broadcast manager sockets are addressed by the index of their
+    /// CAN interface alone.
+    ///
+    /// # References
+    ///
+    /// [SocketCAN](https://docs.kernel.org/networking/can.html)
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CanAddr(pub(in super::super) sockaddr_can);
+
+    impl CanAddr {
+        /// Construct a new socket address for the CAN interface with index
+        /// `ifindex`, or for all CAN interfaces if `ifindex` is 0.
+        pub fn new(ifindex: i32) -> CanAddr {
+            let mut addr: sockaddr_can = unsafe { mem::zeroed() };
+            addr.can_family = AddressFamily::Can as sa_family_t;
+            addr.can_ifindex = ifindex;
+
+            CanAddr(addr)
+        }
+
+        /// Return the index of the CAN interface.
+        pub const fn ifindex(&self) -> i32 {
+            self.0.can_ifindex
+        }
+
+        /// The fields that identify the address, including those of the
+        /// protocol-specific `can_addr` union.  Its `tp` member overlaps
+        /// `j1939.name`, so the `j1939` fields cover both members.
+        fn key(&self) -> (sa_family_t, i32, u64, u32, u8) {
+            // Always initialized: the address is zeroed before being filled
+            let j1939 = unsafe { self.0.can_addr.j1939 };
+            (
+                self.0.can_family,
+                self.0.can_ifindex,
+                j1939.name,
+                j1939.pgn,
+                j1939.addr,
+            )
+        }
+    }
+
+    impl private::SockaddrLikePriv for CanAddr {}
+    impl SockaddrLike for CanAddr {
+        unsafe fn from_raw(
+            addr: *const libc::sockaddr,
+            len: Option<libc::socklen_t>,
+        ) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            // Raw and broadcast manager sockets only report the address up
+            // to `can_ifindex`, leaving out the protocol-specific `can_addr`.
+            let len = len.map_or(mem::size_of::<sockaddr_can>(), |l| l as usize);
+            if len < offset_of!(sockaddr_can, can_addr)
+                || len > mem::size_of::<sockaddr_can>()
+            {
+                return None;
+            }
+            if unsafe { (*addr).sa_family as i32 != libc::AF_CAN } {
+                return None;
+            }
+            let mut can = mem::MaybeUninit::<sockaddr_can>::zeroed();
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    addr.cast::<u8>(),
+                    can.as_mut_ptr().cast::<u8>(),
+                    len,
+                );
+                Some(Self(can.assume_init()))
+            }
+        }
+    }
+
+    impl AsRef<libc::sockaddr_can> for CanAddr {
+        fn as_ref(&self) -> &libc::sockaddr_can {
+            &self.0
+        }
+    }
+
+    impl PartialEq for CanAddr {
+        fn eq(&self, other: &Self) -> bool {
+            self.key() == other.key()
+        }
+    }
+
+    impl Eq for CanAddr {}
+
+    impl Hash for CanAddr {
+        fn hash<H: Hasher>(&self, s: &mut H) {
+            self.key().hash(s);
+        }
+    }
+
+    impl fmt::Display for CanAddr {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "ifindex: {}", self.ifindex())
+        }
+    }
+
+    impl fmt::Debug for CanAddr {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.debug_struct("CanAddr")
+                .field("ifindex", &self.ifindex())
+                .finish()
+        }
+    }
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+pub mod xdp {
+    use super::*;
+    use crate::sys::socket::addr::AddressFamily;
+    use libc::{sa_family_t, sockaddr_xdp};
+    use std::os::unix::io::{AsFd, AsRawFd};
+    use std::{fmt, mem};
+
+    libc_bitflags! {
+        /// Flags for binding an `AF_XDP` socket.
+        pub struct XdpFlags: u16 {
+            /// Share the UMEM of the socket given as
+            /// [`shared_umem_fd`](XdpAddr::shared_umem_fd).
+            XDP_SHARED_UMEM;
+            /// Force copy mode.
+            XDP_COPY;
+            /// Force zero-copy mode.
+            XDP_ZEROCOPY;
+            /// Only wake up the kernel when it asks for it.
+            XDP_USE_NEED_WAKEUP;
+        }
+    }
+
+    /// Socket address for Express Data Path sockets, binding them to a queue
+    /// of a network interface.
+    ///
+    /// # References
+    ///
+    /// [AF_XDP](https://docs.kernel.org/networking/af_xdp.html)
+    #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    #[repr(transparent)]
+    pub struct XdpAddr(pub(in super::super) sockaddr_xdp);
+
+    impl XdpAddr {
+        /// Construct a new socket address from the index of an interface,
+        /// one of its queues, and binding flags.
+        pub fn new(ifindex: u32, queue_id: u32, flags: XdpFlags) -> XdpAddr {
+            let mut addr: sockaddr_xdp = unsafe { mem::zeroed() };
+            addr.sxdp_family = AddressFamily::Xdp as sa_family_t;
+            addr.sxdp_flags = flags.bits();
+            addr.sxdp_ifindex = ifindex;
+            addr.sxdp_queue_id = queue_id;
+
+            XdpAddr(addr)
+        }
+
+        /// Construct a socket address sharing the UMEM already registered
+        /// with the `AF_XDP` socket `fd`.
+        ///
+        /// [`XDP_SHARED_UMEM`](XdpFlags::XDP_SHARED_UMEM) is added to
+        /// `flags`.
+        pub fn new_shared_umem<Fd: AsFd>(
+            ifindex: u32,
+            queue_id: u32,
+            flags: XdpFlags,
+            fd: Fd,
+        ) -> XdpAddr {
+            let mut addr =
+                Self::new(ifindex, queue_id, flags | XdpFlags::XDP_SHARED_UMEM);
+            addr.0.sxdp_shared_umem_fd = fd.as_fd().as_raw_fd() as u32;
+            addr
+        }
+
+        /// Return the binding flags.
+        pub const fn flags(&self) -> XdpFlags {
+            XdpFlags::from_bits_truncate(self.0.sxdp_flags)
+        }
+
+        /// Return the index of the network interface.
+        pub const fn ifindex(&self) -> u32 {
+            self.0.sxdp_ifindex
+        }
+
+        /// Return the queue of the network interface.
+        pub const fn queue_id(&self) -> u32 {
+            self.0.sxdp_queue_id
+        }
+
+        /// Return the socket whose UMEM is shared, if
+        /// [`XDP_SHARED_UMEM`](XdpFlags::XDP_SHARED_UMEM) is set.
+        pub const fn shared_umem_fd(&self) -> u32 {
+            self.0.sxdp_shared_umem_fd
+        }
+    }
+
+    impl private::SockaddrLikePriv for XdpAddr {}
+    impl SockaddrLike for XdpAddr {
+        unsafe fn from_raw(
+            addr: *const libc::sockaddr,
+            len: Option<libc::socklen_t>,
+        ) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            if let Some(l) = len {
+                if l != mem::size_of::<libc::sockaddr_xdp>() as libc::socklen_t
+                {
+                    return None;
+                }
+            }
+            if unsafe { (*addr).sa_family as i32 != libc::AF_XDP } {
+                return None;
+            }
+            Some(Self(unsafe { ptr::read_unaligned(addr as *const _) }))
+        }
+    }
+
+    impl AsRef<libc::sockaddr_xdp> for XdpAddr {
+        fn as_ref(&self) -> &libc::sockaddr_xdp {
+            &self.0
+        }
+    }
+
+    impl fmt::Display for XdpAddr {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f,
+                "ifindex: {} queue: {}",
+                self.ifindex(),
+                self.queue_id()
+            )
+        }
+    }
+}
+
 #[cfg(linux_android)]
 pub mod alg {
     use super::*;
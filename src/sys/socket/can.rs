@@ -0,0 +1,234 @@
+//! Helpers for the SocketCAN broadcast manager (`CAN_BCM`).
+//!
+//! A broadcast manager socket is created with
+//! [`SockProtocol::CanBcm`](super::SockProtocol::CanBcm) and
+//! [`connect`](super::connect)ed to a [`CanAddr`](super::CanAddr).  It is then
+//! driven by exchanging messages, each made of a `bcm_msg_head` followed by
+//! zero or more CAN frames.
+//!
+//! [Further reading](https://docs.kernel.org/networking/can.html#broadcast-manager-protocol-sockets-sock-dgram)
+use crate::errno::Errno;
+use crate::sys::time::TimeVal;
+use crate::Result;
+use std::os::unix::io::{AsFd, AsRawFd};
+use std::{mem, ptr};
+
+/// The most frames the kernel accepts in a single broadcast manager message.
+const MAX_NFRAMES: usize = 256;
+
+libc_enum! {
+    /// Operation carried by a broadcast manager message.
+    #[repr(u32)]
+    #[non_exhaustive]
+    pub enum BcmOpcode {
+        /// Create or update a cyclic transmission task.
+        TX_SETUP,
+        /// Remove a cyclic transmission task.
+        TX_DELETE,
+        /// Read the properties of a cyclic transmission task.
+        TX_READ,
+        /// Send one CAN frame.
+        TX_SEND,
+        /// Create or update a receive filter subscription.
+        RX_SETUP,
+        /// Remove a receive filter subscription.
+        RX_DELETE,
+        /// Read the properties of a receive filter subscription.
+        RX_READ,
+        /// Reply to `TX_READ`.
+        TX_STATUS,
+        /// Notification that a cyclic transmission ran out of its count.
+        TX_EXPIRED,
+        /// Reply to `RX_READ`.
+        RX_STATUS,
+        /// Notification that a cyclic message was not received in time.
+        RX_TIMEOUT,
+        /// Notification of an updated CAN frame.
+        RX_CHANGED,
+    }
+    impl TryFrom<u32>
+}
+
+libc_bitflags! {
+    /// Flags of a broadcast manager message.
+    pub struct BcmFlags: u32 {
+        /// Set the intervals `ival1`, `ival2` and `count` of the task.
+        SETTIMER;
+        /// Start the timer with the intervals of the message.
+        STARTTIMER;
+        /// Notify with `TX_EXPIRED` once `count` runs out.
+        TX_COUNTEVT;
+        /// Send the frame immediately when the task is set up.
+        TX_ANNOUNCE;
+        /// Copy the message's `can_id` into every frame.
+        TX_CP_CAN_ID;
+        /// Filter by `can_id` alone, without a frame.
+        RX_FILTER_ID;
+        /// Also report changes of the data length.
+        RX_CHECK_DLC;
+        /// Don't reset the timeout monitor on reception.
+        RX_NO_AUTOTIMER;
+        /// Report a restarted cyclic message after `RX_TIMEOUT`.
+        RX_ANNOUNCE_RESUME;
+        /// Restart a multi-frame transmission from the first frame.
+        TX_RESET_MULTI_IDX;
+        /// Reply to remote transmission requests.
+        RX_RTR_FRAME;
+    }
+}
+
+/// A broadcast manager message: a `bcm_msg_head` and its CAN frames.
+///
+/// Only classic `can_frame`s are supported, not CAN FD frames.
+///
+/// # Example
+///
+/// Send a frame with id `0x123` every 100ms:
+/// ```no_run
+/// # use nix::sys::socket::can::{send_bcm, BcmFlags, BcmOpcode, CanBcmFrame};
+/// # use nix::sys::time::{TimeVal, TimeValLike};
+/// # use std::os::unix::io::OwnedFd;
+/// # let fd: OwnedFd = unimplemented!();
+/// let msg = CanBcmFrame::new(BcmOpcode::TX_SETUP, 0x123)
+///     .flags(BcmFlags::SETTIMER | BcmFlags::STARTTIMER)
+///     .ival2(TimeVal::milliseconds(100))
+///     .frame(0x123, &[0xde, 0xad]);
+/// send_bcm(&fd, &msg).unwrap();
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CanBcmFrame {
+    head: libc::bcm_msg_head,
+    frames: Vec<libc::can_frame>,
+}
+
+impl CanBcmFrame {
+    /// Create a message for `opcode` concerning the CAN id `can_id`.
+    pub fn new(opcode: BcmOpcode, can_id: u32) -> Self {
+        let mut head: libc::bcm_msg_head = unsafe { mem::zeroed() };
+        head.opcode = opcode as u32;
+        head.can_id = can_id;
+        CanBcmFrame {
+            head,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Set the message's flags.
+    pub fn flags(mut self, flags: BcmFlags) -> Self {
+        self.head.flags = flags.bits();
+        self
+    }
+
+    /// Set how many times to send at the `ival1` interval, before switching
+    /// to `ival2`.
+    pub fn count(mut self, count: u32) -> Self {
+        self.head.count = count;
+        self
+    }
+
+    /// Set the interval used for the first `count` transmissions.
+    pub fn ival1(mut self, ival: TimeVal) -> Self {
+        self.head.ival1 = to_bcm_timeval(ival);
+        self
+    }
+
+    /// Set the interval used after the first `count` transmissions.
+    pub fn ival2(mut self, ival: TimeVal) -> Self {
+        self.head.ival2 = to_bcm_timeval(ival);
+        self
+    }
+
+    /// Append a CAN frame with id `can_id` and payload `data`.
+    ///
+    /// # Panics
+    ///
+    /// If `data` is longer than 8 bytes, or the message already has the most
+    /// frames the kernel accepts.
+    pub fn frame(mut self, can_id: u32, data: &[u8]) -> Self {
+        assert!(self.frames.len() < MAX_NFRAMES, "too many BCM frames");
+        let mut frame: libc::can_frame = unsafe { mem::zeroed() };
+        frame.can_id = can_id;
+        frame.can_dlc = data.len() as u8;
+        frame.data[..data.len()].copy_from_slice(data);
+        self.frames.push(frame);
+        self
+    }
+
+    /// The operation of the message.
+    pub fn opcode(&self) -> Result<BcmOpcode> {
+        BcmOpcode::try_from(self.head.opcode)
+    }
+
+    /// The CAN id the message is about.
+    pub fn can_id(&self) -> u32 {
+        self.head.can_id
+    }
+
+    /// The CAN frames carried by the message.
+    pub fn frames(&self) -> &[libc::can_frame] {
+        &self.frames
+    }
+}
+
+fn to_bcm_timeval(tv: TimeVal) -> libc::bcm_timeval {
+    libc::bcm_timeval {
+        tv_sec: tv.tv_sec() as libc::c_long,
+        tv_usec: tv.tv_usec() as libc::c_long,
+    }
+}
+
+/// Send a message to a broadcast manager socket.
+///
+/// Returns the number of bytes sent.
+pub fn send_bcm<F: AsFd>(fd: &F, msg: &CanBcmFrame) -> Result<usize> {
+    let head_len = mem::size_of::<libc::bcm_msg_head>();
+    let frame_len = mem::size_of::<libc::can_frame>();
+    let mut buf = vec![0u8; head_len + msg.frames.len() * frame_len];
+
+    let mut head = msg.head;
+    head.nframes = msg.frames.len() as u32;
+    unsafe {
+        ptr::write_unaligned(buf.as_mut_ptr().cast(), head);
+        for (i, frame) in msg.frames.iter().enumerate() {
+            ptr::write_unaligned(
+                buf.as_mut_ptr().add(head_len + i * frame_len).cast(),
+                *frame,
+            );
+        }
+    }
+
+    let res = unsafe {
+        libc::write(fd.as_fd().as_raw_fd(), buf.as_ptr().cast(), buf.len())
+    };
+    Errno::result(res).map(|r| r as usize)
+}
+
+/// Receive a message from a broadcast manager socket.
+///
+/// Fails with `EBADMSG` if what was read is too short to be a message.
+pub fn recv_bcm<F: AsFd>(fd: &F) -> Result<CanBcmFrame> {
+    let head_len = mem::size_of::<libc::bcm_msg_head>();
+    let frame_len = mem::size_of::<libc::can_frame>();
+    let mut buf = vec![0u8; head_len + MAX_NFRAMES * frame_len];
+
+    let res = unsafe {
+        libc::read(fd.as_fd().as_raw_fd(), buf.as_mut_ptr().cast(), buf.len())
+    };
+    let len = Errno::result(res)? as usize;
+    if len < head_len {
+        return Err(Errno::EBADMSG);
+    }
+
+    let mut head: libc::bcm_msg_head =
+        unsafe { ptr::read_unaligned(buf.as_ptr().cast()) };
+    let nframes = (head.nframes as usize).min((len - head_len) / frame_len);
+    head.nframes = 0;
+    let frames = (0..nframes)
+        .map(|i| unsafe {
+            ptr::read_unaligned(
+                buf.as_ptr().add(head_len + i * frame_len).cast(),
+            )
+        })
+        .collect();
+    Ok(CanBcmFrame { head, frames })
+}
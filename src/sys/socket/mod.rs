@@ -24,6 +24,9 @@ use std::{mem, ptr};
 
 #[deny(missing_docs)]
 mod addr;
+#[cfg(target_os = "linux")]
+#[deny(missing_docs)]
+pub mod can;
 #[deny(missing_docs)]
 pub mod sockopt;
 
@@ -58,6 +61,8 @@ pub use self::addr::{SockaddrIn, SockaddrIn6};
 
 #[cfg(linux_android)]
 pub use crate::sys::socket::addr::alg::AlgAddr;
+#[cfg(target_os = "linux")]
+pub use crate::sys::socket::addr::can::CanAddr;
 #[cfg(linux_android)]
 pub use crate::sys::socket::addr::netlink::NetlinkAddr;
 #[cfg(apple_targets)]
@@ -65,6 +70,8 @@ pub use crate::sys::socket::addr::netlink::NetlinkAddr;
 pub use crate::sys::socket::addr::sys_control::SysControlAddr;
 #[cfg(any(linux_android, apple_targets))]
 pub use crate::sys::socket::addr::vsock::VsockAddr;
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+pub use crate::sys::socket::addr::xdp::{XdpAddr, XdpFlags};
 
 #[cfg(all(feature = "uio", not(target_os = "redox")))]
 pub use libc::{cmsghdr, msghdr};
@@ -367,6 +374,15 @@ libc_bitflags! {
         /// Indicates that this message is not a user message but an SCTP notification.
         #[cfg(target_os = "linux")]
         MSG_NOTIFICATION;
+        /// Send the data without copying it into the kernel.  Requires
+        /// [`ZeroCopy`](sockopt::ZeroCopy) to be enabled on the socket.  The
+        /// buffer must not be modified until the kernel reports completion
+        /// with a [`ControlMessageOwned::ZeroCopyCompletion`] on the error
+        /// queue.
+        ///
+        /// [Further reading](https://docs.kernel.org/networking/msg_zerocopy.html)
+        #[cfg(target_os = "linux")]
+        MSG_ZEROCOPY;
     }
 }
 
@@ -577,6 +593,59 @@ cfg_if! {
     }
 }
 
+/// Return type of [`TcpInfo`](crate::sys::socket::sockopt::TcpInfo)
+///
+/// A snapshot of the kernel's internal state for a TCP connection.  Fields
+/// that the running kernel does not know about are reported as zero.
+#[cfg(all(target_os = "linux", feature = "net"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "net")))]
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TcpInfo(libc::tcp_info);
+
+#[cfg(all(target_os = "linux", feature = "net"))]
+impl TcpInfo {
+    /// The state of the connection, as one of the kernel's `TCP_*` states
+    /// (e.g. `TCP_ESTABLISHED` is `1`).
+    pub fn state(&self) -> u8 {
+        self.0.tcpi_state
+    }
+
+    /// Smoothed round trip time, in microseconds.
+    pub fn rtt(&self) -> u32 {
+        self.0.tcpi_rtt
+    }
+
+    /// Round trip time variance, in microseconds.
+    pub fn rttvar(&self) -> u32 {
+        self.0.tcpi_rttvar
+    }
+
+    /// Sending congestion window, in segments.
+    pub fn snd_cwnd(&self) -> u32 {
+        self.0.tcpi_snd_cwnd
+    }
+
+    /// Number of segments currently being retransmitted.
+    pub fn retrans(&self) -> u32 {
+        self.0.tcpi_retrans
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "net"))]
+impl From<libc::tcp_info> for TcpInfo {
+    fn from(info: libc::tcp_info) -> Self {
+        TcpInfo(info)
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "net"))]
+impl From<TcpInfo> for libc::tcp_info {
+    fn from(info: TcpInfo) -> Self {
+        info.0
+    }
+}
+
 feature! {
 #![feature = "net"]
 /// Request for multicast socket operations
@@ -694,6 +763,25 @@ impl<S> RecvMsg<'_, '_, S> {
     }
 }
 
+impl RecvMsg<'_, '_, SockaddrStorage> {
+    /// Downcast the sender's address to an IPv4 address, if it is one.
+    #[cfg(feature = "net")]
+    pub fn source_in(&self) -> Option<&SockaddrIn> {
+        self.address.as_ref()?.as_sockaddr_in()
+    }
+
+    /// Downcast the sender's address to an IPv6 address, if it is one.
+    #[cfg(feature = "net")]
+    pub fn source_in6(&self) -> Option<&SockaddrIn6> {
+        self.address.as_ref()?.as_sockaddr_in6()
+    }
+
+    /// Downcast the sender's address to a Unix domain address, if it is one.
+    pub fn source_unix(&self) -> Option<&UnixAddr> {
+        self.address.as_ref()?.as_unix_addr()
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct CmsgIterator<'a> {
     /// Control message buffer to decode from. Must adhere to cmsg alignment.
@@ -873,12 +961,19 @@ pub enum ControlMessageOwned {
     Ipv6HopLimit(i32),
 
     /// Retrieve the DSCP (ToS) header field of the incoming IPv4 packet.
+    ///
+    /// Only delivered once [`IpRecvTos`](crate::sys::socket::sockopt::IpRecvTos)
+    /// has been enabled on the socket.
     #[cfg(any(linux_android, target_os = "freebsd"))]
     #[cfg(feature = "net")]
     #[cfg_attr(docsrs, doc(cfg(feature = "net")))]
     Ipv4Tos(u8),
 
     /// Retrieve the DSCP (Traffic Class) header field of the incoming IPv6 packet.
+    ///
+    /// Only delivered once
+    /// [`Ipv6RecvTClass`](crate::sys::socket::sockopt::Ipv6RecvTClass) has
+    /// been enabled on the socket.
     #[cfg(any(linux_android, target_os = "freebsd"))]
     #[cfg(feature = "net")]
     #[cfg_attr(docsrs, doc(cfg(feature = "net")))]
@@ -918,6 +1013,18 @@ pub enum ControlMessageOwned {
     #[cfg(feature = "net")]
     #[cfg_attr(docsrs, doc(cfg(feature = "net")))]
     Ipv6RecvErr(libc::sock_extended_err, Option<sockaddr_in6>),
+    /// Completion notification of `MSG_ZEROCOPY` sends, read from the socket
+    /// error queue with the `MSG_ERRQUEUE` flag.
+    ///
+    /// These are reported here instead of as [`Ipv4RecvErr`] or
+    /// [`Ipv6RecvErr`].
+    ///
+    /// [`Ipv4RecvErr`]: ControlMessageOwned::Ipv4RecvErr
+    /// [`Ipv6RecvErr`]: ControlMessageOwned::Ipv6RecvErr
+    #[cfg(target_os = "linux")]
+    #[cfg(feature = "net")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "net")))]
+    ZeroCopyCompletion(ZeroCopyCompletion),
 
     /// `SOL_TLS` messages of type `TLS_GET_RECORD_TYPE`
     #[cfg(any(target_os = "linux"))]
@@ -939,6 +1046,64 @@ pub struct Timestamps {
     pub hw_raw: TimeSpec,
 }
 
+#[cfg(linux_android)]
+impl Timestamps {
+    /// The software timestamp, if the kernel generated one.
+    pub fn software(&self) -> Option<TimeSpec> {
+        Some(self.system).filter(|ts| *ts != TimeSpec::new(0, 0))
+    }
+
+    /// The raw hardware timestamp, if the network device generated one.
+    pub fn hardware_raw(&self) -> Option<TimeSpec> {
+        Some(self.hw_raw).filter(|ts| *ts != TimeSpec::new(0, 0))
+    }
+
+    /// Whether none of the timestamps were filled in.
+    pub fn is_empty(&self) -> bool {
+        let zero = TimeSpec::new(0, 0);
+        self.system == zero && self.hw_trans == zero && self.hw_raw == zero
+    }
+}
+
+// Not yet exposed by libc.
+#[cfg(target_os = "linux")]
+#[cfg(feature = "net")]
+const SO_EE_ORIGIN_ZEROCOPY: u8 = 5;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "net")]
+const SO_EE_CODE_ZEROCOPY_COPIED: u8 = 1;
+
+/// A range of completed `MSG_ZEROCOPY` sends.
+///
+/// Each successful `MSG_ZEROCOPY` send on a socket is numbered, starting from
+/// 0.  A notification reports that the buffers of all sends in
+/// [`range`](Self::range) may be reused.
+#[cfg(target_os = "linux")]
+#[cfg(feature = "net")]
+#[cfg_attr(docsrs, doc(cfg(feature = "net")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ZeroCopyCompletion {
+    lo: u32,
+    hi: u32,
+    copied: bool,
+}
+
+#[cfg(target_os = "linux")]
+#[cfg(feature = "net")]
+impl ZeroCopyCompletion {
+    /// The sequence numbers of the completed sends.
+    pub fn range(&self) -> std::ops::RangeInclusive<u32> {
+        self.lo..=self.hi
+    }
+
+    /// Whether the kernel fell back to copying the data.
+    ///
+    /// If so, later sends are likely better off without `MSG_ZEROCOPY`.
+    pub fn copied(&self) -> bool {
+        self.copied
+    }
+}
+
 /// These constants correspond to TLS 1.2 message types, as defined in
 /// RFC 5246, Appendix A.1
 #[cfg(any(target_os = "linux"))]
@@ -1082,12 +1247,20 @@ impl ControlMessageOwned {
             #[cfg(feature = "net")]
             (libc::IPPROTO_IP, libc::IP_RECVERR) => {
                 let (err, addr) = unsafe { Self::recv_err_helper::<sockaddr_in>(p, len) };
+                #[cfg(target_os = "linux")]
+                if err.ee_origin == SO_EE_ORIGIN_ZEROCOPY {
+                    return ControlMessageOwned::zerocopy_completion(&err);
+                }
                 ControlMessageOwned::Ipv4RecvErr(err, addr)
             },
             #[cfg(linux_android)]
             #[cfg(feature = "net")]
             (libc::IPPROTO_IPV6, libc::IPV6_RECVERR) => {
                 let (err, addr) = unsafe { Self::recv_err_helper::<sockaddr_in6>(p, len) };
+                #[cfg(target_os = "linux")]
+                if err.ee_origin == SO_EE_ORIGIN_ZEROCOPY {
+                    return ControlMessageOwned::zerocopy_completion(&err);
+                }
                 ControlMessageOwned::Ipv6RecvErr(err, addr)
             },
             #[cfg(any(linux_android, target_os = "freebsd"))]
@@ -1167,6 +1340,16 @@ impl ControlMessageOwned {
             (err, Some(unsafe { ptr::read_unaligned(addrp) }))
         }
     }
+
+    #[cfg(target_os = "linux")]
+    #[cfg(feature = "net")]
+    fn zerocopy_completion(err: &libc::sock_extended_err) -> ControlMessageOwned {
+        ControlMessageOwned::ZeroCopyCompletion(ZeroCopyCompletion {
+            lo: err.ee_info,
+            hi: err.ee_data,
+            copied: err.ee_code & SO_EE_CODE_ZEROCOPY_COPIED != 0,
+        })
+    }
 }
 
 /// A type-safe zero-copy wrapper around a single control message, as used with
@@ -1734,7 +1917,7 @@ pub fn sendmsg<S>(fd: RawFd, iov: &[IoSlice<'_>], cmsgs: &[ControlMessage],
 ///
 /// # References
 /// [`sendmsg`](fn.sendmsg.html)
-#[cfg(any(linux_android, target_os = "freebsd", target_os = "netbsd"))]
+#[cfg(any(linux_android, target_os = "freebsd", netbsdlike))]
 pub fn sendmmsg<'a, XS, AS, C, I, S>(
     fd: RawFd,
     data: &'a mut MultiHeaders<S>,
@@ -1805,12 +1988,27 @@ pub fn sendmmsg<'a, XS, AS, C, I, S>(
 }
 
 
-#[cfg(any(linux_android, target_os = "freebsd", target_os = "netbsd"))]
+#[cfg(any(linux_android, target_os = "freebsd", netbsdlike))]
+use libc::mmsghdr;
+
+// Apple's <sys/socket.h> has no recvmmsg, and so no mmsghdr.  This mirrors the
+// Linux layout so that MultiHeaders is the same everywhere; it is never passed
+// to the OS.
+#[cfg(apple_targets)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+#[allow(non_camel_case_types)]
+struct mmsghdr {
+    msg_hdr: libc::msghdr,
+    msg_len: libc::c_uint,
+}
+
+#[cfg(any(linux_android, target_os = "freebsd", netbsdlike, apple_targets))]
 #[derive(Debug)]
 /// Preallocated structures needed for [`recvmmsg`] and [`sendmmsg`] functions
 pub struct MultiHeaders<S> {
     // preallocated boxed slice of mmsghdr
-    items: Box<[libc::mmsghdr]>,
+    items: Box<[mmsghdr]>,
     addresses: Box<[mem::MaybeUninit<S>]>,
     // while we are not using it directly - this is used to store control messages
     // and we retain pointers to them inside items array
@@ -1818,7 +2016,7 @@ pub struct MultiHeaders<S> {
     msg_controllen: usize,
 }
 
-#[cfg(any(linux_android, target_os = "freebsd", target_os = "netbsd"))]
+#[cfg(any(linux_android, target_os = "freebsd", netbsdlike, apple_targets))]
 impl<S> MultiHeaders<S> {
     /// Preallocate structure used by [`recvmmsg`] and [`sendmmsg`] takes number of headers to preallocate
     ///
@@ -1847,7 +2045,7 @@ impl<S> MultiHeaders<S> {
                     None => (std::ptr::null_mut(), 0),
                 };
                 let msg_hdr = unsafe { pack_mhdr_to_receive(std::ptr::null_mut(), 0, ptr, cap, address.as_mut_ptr()) };
-                libc::mmsghdr {
+                mmsghdr {
                     msg_hdr,
                     msg_len: 0,
                 }
@@ -1873,6 +2071,11 @@ impl<S> MultiHeaders<S> {
 /// iterators over [`IoSlice`] with [`iovs`][RecvMsg::iovs`] and
 /// `ControlMessageOwned` with [`cmsgs`][RecvMsg::cmsgs].
 ///
+/// On Apple platforms, which lack `recvmmsg`, it is emulated with one
+/// `recvmsg` call per message.  The emulation follows the behavior described
+/// below: the timeout is only checked after each message, and an error after at
+/// least one message ends the batch, e.g. `EAGAIN` with `MSG_DONTWAIT`.
+///
 /// # Bugs (in underlying implementation, at least in Linux)
 /// The timeout argument does not work as intended. The timeout is checked only after the receipt
 /// of each datagram, so that if up to `vlen`-1 datagrams are received before the timeout expires,
@@ -1886,13 +2089,13 @@ impl<S> MultiHeaders<S> {
 // On aarch64 linux using recvmmsg and trying to get hardware/kernel timestamps might not
 // always produce the desired results - see https://github.com/nix-rust/nix/pull/1744 for more
 // details
-#[cfg(any(linux_android, target_os = "freebsd", target_os = "netbsd"))]
+#[cfg(any(linux_android, target_os = "freebsd", netbsdlike, apple_targets))]
 pub fn recvmmsg<'a, XS, S, I>(
     fd: RawFd,
     data: &'a mut MultiHeaders<S>,
     slices: XS,
     flags: MsgFlags,
-    mut timeout: Option<crate::sys::time::TimeSpec>,
+    timeout: Option<crate::sys::time::TimeSpec>,
 ) -> crate::Result<MultiResults<'a, S>>
 where
     XS: IntoIterator<Item = &'a mut I>,
@@ -1911,21 +2114,28 @@ where
         count = i + 1;
     }
 
-    let timeout_ptr = timeout
-        .as_mut()
-        .map_or_else(std::ptr::null_mut, |t| t as *mut _ as *mut libc::timespec);
-
-    // SAFETY: all pointers are guaranteed to be valid for the scope of this function. `count` does represent the
-    // maximum number of messages that can be received safely (i.e. `count` is the minimum of the sizes of `slices` and `data.items`)
-    let received = Errno::result(unsafe {
-        libc::recvmmsg(
-            fd,
-            data.items.as_mut_ptr(),
-            count as _,
-            flags.bits() as _,
-            timeout_ptr,
-        )
-    })? as usize;
+    #[cfg(apple_targets)]
+    let received = recvmmsg_fallback(fd, &mut data.items[..count], flags, timeout)?;
+
+    #[cfg(not(apple_targets))]
+    let received = {
+        let mut timeout = timeout;
+        let timeout_ptr = timeout
+            .as_mut()
+            .map_or_else(std::ptr::null_mut, |t| t as *mut _ as *mut libc::timespec);
+
+        // SAFETY: all pointers are guaranteed to be valid for the scope of this function. `count` does represent the
+        // maximum number of messages that can be received safely (i.e. `count` is the minimum of the sizes of `slices` and `data.items`)
+        Errno::result(unsafe {
+            libc::recvmmsg(
+                fd,
+                data.items.as_mut_ptr(),
+                count as _,
+                flags.bits() as _,
+                timeout_ptr,
+            )
+        })? as usize
+    };
 
     Ok(MultiResults {
         rmm: data,
@@ -1934,8 +2144,37 @@ where
     })
 }
 
+/// Emulate `recvmmsg` with one `recvmsg` call per message, where the OS lacks
+/// it.  Like `recvmmsg`, this only checks the timeout after each message, and
+/// an error after at least one message ends the batch successfully.
+#[cfg(apple_targets)]
+fn recvmmsg_fallback(
+    fd: RawFd,
+    items: &mut [mmsghdr],
+    flags: MsgFlags,
+    timeout: Option<crate::sys::time::TimeSpec>,
+) -> crate::Result<usize> {
+    let deadline = timeout.map(|t| std::time::Instant::now() + std::time::Duration::from(t));
+    let mut received = 0;
+    for item in items {
+        // SAFETY: the msghdr was set up by `MultiHeaders::preallocate` and
+        // `recvmmsg` with buffers that outlive this call.
+        let res = unsafe { libc::recvmsg(fd, &mut item.msg_hdr, flags.bits()) };
+        match Errno::result(res) {
+            Ok(len) => item.msg_len = len as libc::c_uint,
+            Err(_) if received > 0 => break,
+            Err(e) => return Err(e),
+        }
+        received += 1;
+        if matches!(deadline, Some(d) if std::time::Instant::now() >= d) {
+            break;
+        }
+    }
+    Ok(received)
+}
+
 /// Iterator over results of [`recvmmsg`]/[`sendmmsg`]
-#[cfg(any(linux_android, target_os = "freebsd", target_os = "netbsd"))]
+#[cfg(any(linux_android, target_os = "freebsd", netbsdlike, apple_targets))]
 #[derive(Debug)]
 pub struct MultiResults<'a, S> {
     // preallocated structures
@@ -1944,7 +2183,7 @@ pub struct MultiResults<'a, S> {
     received: usize,
 }
 
-#[cfg(any(linux_android, target_os = "freebsd", target_os = "netbsd"))]
+#[cfg(any(linux_android, target_os = "freebsd", netbsdlike, apple_targets))]
 impl<'a, S> Iterator for MultiResults<'a, S>
 where
     S: Copy + SockaddrLike,
@@ -2346,6 +2585,56 @@ pub fn accept4(sockfd: RawFd, flags: SockFlag) -> Result<RawFd> {
     Errno::result(res)
 }
 
+/// Accept a connection on a socket, and get the peer's address
+///
+/// Like [`accept4`], but also returns the address of the connecting peer, or
+/// `None` if it doesn't fit in a `T`.  [`SockaddrStorage`] fits any address.
+///
+/// [Further reading](https://man7.org/linux/man-pages/man2/accept.2.html)
+#[cfg(any(
+    all(
+        target_os = "android",
+        any(
+            target_arch = "aarch64",
+            target_arch = "x86",
+            target_arch = "x86_64"
+        )
+    ),
+    freebsdlike,
+    netbsdlike,
+    target_os = "emscripten",
+    target_os = "fuchsia",
+    solarish,
+    target_os = "linux",
+))]
+pub fn accept_addr<T: SockaddrLike>(
+    sockfd: RawFd,
+    flags: SockFlag,
+) -> Result<(OwnedFd, Option<T>)> {
+    let mut addr = mem::MaybeUninit::<T>::zeroed();
+    let capacity = mem::size_of::<T>() as socklen_t;
+    let mut len = capacity;
+
+    let res = unsafe {
+        libc::accept4(
+            sockfd,
+            addr.as_mut_ptr().cast(),
+            &mut len as *mut socklen_t,
+            flags.bits(),
+        )
+    };
+    let fd = unsafe { OwnedFd::from_raw_fd(Errno::result(res)?) };
+
+    // The kernel reports the full length of the address even if it had to
+    // truncate it.
+    let addr = if len > capacity {
+        None
+    } else {
+        unsafe { T::from_raw(addr.as_ptr().cast(), Some(len)) }
+    };
+    Ok((fd, addr))
+}
+
 /// Initiate a connection on a socket
 ///
 /// [Further reading](https://pubs.opengroup.org/onlinepubs/9699919799/functions/connect.html)
@@ -2372,6 +2661,22 @@ pub fn recv(sockfd: RawFd, buf: &mut [u8], flags: MsgFlags) -> Result<usize> {
     }
 }
 
+/// Receive a datagram, returning its real length even if it was truncated.
+///
+/// `MSG_TRUNC` is added to `flags`, so the returned length is that of the
+/// whole datagram, which may be larger than `buf`.  In that case only the
+/// first `buf.len()` bytes are stored and the rest is discarded.
+///
+/// [Further reading](https://man7.org/linux/man-pages/man2/recv.2.html)
+#[cfg(linux_android)]
+pub fn recv_trunc<F: AsFd>(
+    fd: &F,
+    buf: &mut [u8],
+    flags: MsgFlags,
+) -> Result<usize> {
+    recv(fd.as_fd().as_raw_fd(), buf, flags | MsgFlags::MSG_TRUNC)
+}
+
 /// Receive data from a connectionless or connection-oriented socket. Returns
 /// the number of bytes read and, for connectionless sockets,  the socket
 /// address of the sender.
@@ -2398,6 +2703,150 @@ pub fn recvfrom<T: SockaddrLike>(
     }
 }
 
+/// Receive a datagram along with the socket's packet drop counter.
+///
+/// Receives one message like [`recvmsg`], returning the number of bytes read,
+/// the sender's address and the number of packets the socket dropped since
+/// its creation.  The kernel only attaches the counter once something was
+/// dropped, so `None` is returned when it is absent.
+///
+/// [`RxqOvfl`](sockopt::RxqOvfl) must be enabled on the socket once, before
+/// any packets arrive; packets queued before that never carry the counter.
+///
+/// [Further reading](https://man7.org/linux/man-pages/man7/socket.7.html)
+#[cfg(any(linux_android, target_os = "fuchsia"))]
+#[cfg(feature = "uio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uio")))]
+pub fn recv_with_drops<F: AsFd, S: SockaddrLike>(
+    fd: &F,
+    buf: &mut [u8],
+    flags: MsgFlags,
+) -> Result<(usize, Option<S>, Option<u32>)> {
+    let mut cmsg_buffer = cmsg_space!(u32);
+    let mut iov = [IoSliceMut::new(buf)];
+    let msg = recvmsg::<S>(
+        fd.as_fd().as_raw_fd(),
+        &mut iov,
+        Some(&mut cmsg_buffer),
+        flags,
+    )?;
+    let drops = msg.cmsgs()?.find_map(|cmsg| match cmsg {
+        ControlMessageOwned::RxqOvfl(drops) => Some(drops),
+        _ => None,
+    });
+    Ok((msg.bytes, msg.address, drops))
+}
+
+/// Receive a message along with its `SO_TIMESTAMPING` timestamps.
+///
+/// The [`Timestamping`](sockopt::Timestamping) socket option must already
+/// have been set with the desired
+/// [`TimestampingFlag`](crate::sys::socket::TimestampingFlag)s.  Returns the
+/// number of bytes read, and the timestamps if the kernel attached any.
+///
+/// [Further reading](https://www.kernel.org/doc/html/latest/networking/timestamping.html)
+#[cfg(linux_android)]
+#[cfg(feature = "uio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uio")))]
+pub fn recv_hw_timestamp<F: AsFd>(
+    fd: &F,
+    buf: &mut [u8],
+) -> Result<(usize, Option<Timestamps>)> {
+    let mut cmsg_buffer = cmsg_space!(Timestamps);
+    let mut iov = [IoSliceMut::new(buf)];
+    let msg = recvmsg::<()>(
+        fd.as_fd().as_raw_fd(),
+        &mut iov,
+        Some(&mut cmsg_buffer),
+        MsgFlags::empty(),
+    )?;
+    let timestamps = msg.cmsgs()?.find_map(|cmsg| match cmsg {
+        ControlMessageOwned::ScmTimestampsns(timestamps) => Some(timestamps),
+        _ => None,
+    });
+    Ok((msg.bytes, timestamps))
+}
+
+/// Send data along with file descriptors, using `SCM_RIGHTS`.
+///
+/// Returns the number of bytes sent.  The descriptors are received with
+/// [`recv_fds`] on the other end of the socket.
+///
+/// [Further reading](https://man7.org/linux/man-pages/man7/unix.7.html)
+#[cfg(feature = "uio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uio")))]
+pub fn send_fds<F: AsFd>(fd: &F, fds: &[RawFd], buf: &[u8]) -> Result<usize> {
+    let iov = [IoSlice::new(buf)];
+    let cmsgs = [ControlMessage::ScmRights(fds)];
+    sendmsg::<()>(
+        fd.as_fd().as_raw_fd(),
+        &iov,
+        &cmsgs,
+        MsgFlags::empty(),
+        None,
+    )
+}
+
+/// Receive data along with file descriptors passed by `SCM_RIGHTS`.
+///
+/// At most `max_fds` descriptors are received; the kernel closes any extra
+/// ones.  Returns the number of bytes read and the received descriptors.
+///
+/// The received descriptors always have the close-on-exec flag set.  Where
+/// supported, this is done atomically with `MSG_CMSG_CLOEXEC`.  On other
+/// platforms, the flag is set with `fcntl` right after receiving, so another
+/// thread calling `exec` in between may still leak them.
+///
+/// [Further reading](https://man7.org/linux/man-pages/man7/unix.7.html)
+#[cfg(feature = "uio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uio")))]
+pub fn recv_fds<F: AsFd>(
+    fd: &F,
+    buf: &mut [u8],
+    max_fds: usize,
+) -> Result<(usize, Vec<OwnedFd>)> {
+    cfg_if! {
+        if #[cfg(any(linux_android, freebsdlike, netbsdlike))] {
+            let flags = MsgFlags::MSG_CMSG_CLOEXEC;
+        } else {
+            let flags = MsgFlags::empty();
+        }
+    }
+
+    let space = unsafe {
+        libc::CMSG_SPACE((max_fds * mem::size_of::<RawFd>()) as libc::c_uint)
+    };
+    let mut cmsg_buffer = vec![0u8; space as usize];
+    let mut iov = [IoSliceMut::new(buf)];
+    let msg = recvmsg::<()>(
+        fd.as_fd().as_raw_fd(),
+        &mut iov,
+        Some(&mut cmsg_buffer),
+        flags,
+    )?;
+    let mut fds = Vec::new();
+    for cmsg in msg.cmsgs()? {
+        if let ControlMessageOwned::ScmRights(raw_fds) = cmsg {
+            // SAFETY: the kernel just handed us these descriptors
+            fds.extend(
+                raw_fds
+                    .into_iter()
+                    .map(|raw_fd| unsafe { OwnedFd::from_raw_fd(raw_fd) }),
+            );
+        }
+    }
+
+    #[cfg(not(any(linux_android, freebsdlike, netbsdlike)))]
+    for fd in &fds {
+        let res = unsafe {
+            libc::fcntl(fd.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC)
+        };
+        Errno::result(res)?;
+    }
+
+    Ok((msg.bytes, fds))
+}
+
 /// Send a message to a socket
 ///
 /// [Further reading](https://pubs.opengroup.org/onlinepubs/9699919799/functions/sendto.html)
@@ -2432,6 +2881,24 @@ pub fn send(fd: RawFd, buf: &[u8], flags: MsgFlags) -> Result<usize> {
     Errno::result(ret).map(|r| r as usize)
 }
 
+/// Determine whether a socket is at the out-of-band mark.
+///
+/// Returns `true` if the next byte to be read from the socket is the one
+/// that was sent with `MSG_OOB`, i.e. all normal data preceding the urgent
+/// data has already been read.
+///
+/// [Further reading](https://pubs.opengroup.org/onlinepubs/9699919799/functions/sockatmark.html)
+#[cfg(not(target_os = "redox"))]
+pub fn sockatmark<F: AsFd>(fd: &F) -> Result<bool> {
+    // sockatmark(3) is part of POSIX, but is not exported by libc
+    extern "C" {
+        fn sockatmark(fd: c_int) -> c_int;
+    }
+    let res = unsafe { sockatmark(fd.as_fd().as_raw_fd()) };
+
+    Errno::result(res).map(|r| r == 1)
+}
+
 /*
  *
  * ===== Socket Options =====
@@ -2485,6 +2952,60 @@ pub fn setsockopt<F: AsFd, O: SetSockOpt>(
     opt.set(fd, val)
 }
 
+/// Get the raw value of an arbitrary socket option.
+///
+/// This is an escape hatch for socket options that have no typed wrapper in
+/// [`sockopt`].  The value is written into `buf`, and the number of bytes the
+/// kernel stored there is returned.  Prefer [`getsockopt`] whenever a typed
+/// option is available.
+///
+/// [Further reading](https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html)
+pub fn getsockopt_raw<F: AsFd>(
+    fd: &F,
+    level: i32,
+    name: i32,
+    buf: &mut [u8],
+) -> Result<usize> {
+    let mut len = buf.len() as socklen_t;
+    let res = unsafe {
+        libc::getsockopt(
+            fd.as_fd().as_raw_fd(),
+            level,
+            name,
+            buf.as_mut_ptr().cast(),
+            &mut len,
+        )
+    };
+
+    Errno::result(res).map(|_| len as usize)
+}
+
+/// Set the raw value of an arbitrary socket option.
+///
+/// This is an escape hatch for socket options that have no typed wrapper in
+/// [`sockopt`].  `buf` is passed to the kernel as-is.  Prefer [`setsockopt`]
+/// whenever a typed option is available.
+///
+/// [Further reading](https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html)
+pub fn setsockopt_raw<F: AsFd>(
+    fd: &F,
+    level: i32,
+    name: i32,
+    buf: &[u8],
+) -> Result<()> {
+    let res = unsafe {
+        libc::setsockopt(
+            fd.as_fd().as_raw_fd(),
+            level,
+            name,
+            buf.as_ptr().cast(),
+            buf.len() as socklen_t,
+        )
+    };
+
+    Errno::result(res).map(drop)
+}
+
 /// Get the address of the peer connected to the socket `fd`.
 ///
 /// [Further reading](https://pubs.opengroup.org/onlinepubs/9699919799/functions/getpeername.html)
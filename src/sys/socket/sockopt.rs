@@ -57,6 +57,7 @@ macro_rules! setsockopt_impl {
                 use std::os::fd::AsRawFd;
                 use $crate::sys::socket::sockopt::Set;
                 let setter: $setter = Set::new(val);
+                setter.validate()?;
                 let level = $level;
                 let flag = $flag;
                 let res = unsafe {
@@ -223,11 +224,11 @@ macro_rules! sockopt_impl {
     };
 
     ($(#[$attr:meta])* $name:ident, Both, $level:expr, $flag:path,
-     OsString<$array:ty>) =>
+     OsString<[u8; $len:expr]>) =>
     {
         sockopt_impl!($(#[$attr])*
-                      $name, Both, $level, $flag, std::ffi::OsString, $crate::sys::socket::sockopt::GetOsString<$array>,
-                      $crate::sys::socket::sockopt::SetOsString);
+                      $name, Both, $level, $flag, std::ffi::OsString, $crate::sys::socket::sockopt::GetOsString<[u8; $len]>,
+                      $crate::sys::socket::sockopt::SetOsString<{ $len }>);
     };
 
     /*
@@ -312,6 +313,12 @@ sockopt_impl!(
 sockopt_impl!(
     /// Enables incoming connections to be distributed among N sockets (up to 256)
     /// via a Load-Balancing hash based algorithm.
+    ///
+    /// Like [`ReusePort`], this allows several sockets to bind the same
+    /// address, as long as all of them set the option before binding.  Unlike
+    /// plain `SO_REUSEPORT`, incoming TCP connections and UDP datagrams are
+    /// spread across the sockets by a hash of the local port and the foreign
+    /// address and port.
     ReusePortLb,
     Both,
     libc::SOL_SOCKET,
@@ -559,6 +566,8 @@ sockopt_impl!(
 );
 sockopt_impl!(
     /// Specify the receiving timeout until reporting an error.
+    ///
+    /// A zero `TimeVal` disables the timeout, which is the default.
     ReceiveTimeout,
     Both,
     libc::SOL_SOCKET,
@@ -567,6 +576,8 @@ sockopt_impl!(
 );
 sockopt_impl!(
     /// Specify the sending timeout until reporting an error.
+    ///
+    /// A zero `TimeVal` disables the timeout, which is the default.
     SendTimeout,
     Both,
     libc::SOL_SOCKET,
@@ -662,6 +673,16 @@ sockopt_impl!(
     libc::SO_PEERCRED,
     super::UnixCredentials
 );
+#[cfg(target_os = "linux")]
+sockopt_impl!(
+    /// Get the cookie uniquely identifying this socket for as long as the
+    /// system is up.
+    Cookie,
+    GetOnly,
+    libc::SOL_SOCKET,
+    libc::SO_COOKIE,
+    u64
+);
 #[cfg(target_os = "freebsd")]
 #[cfg(feature = "net")]
 sockopt_impl!(
@@ -841,6 +862,9 @@ sockopt_impl!(
 #[cfg(linux_android)]
 sockopt_impl!(
     /// Bind this socket to a particular device like “eth0”.
+    ///
+    /// The name must be shorter than `IFNAMSIZ` bytes.  Setting an empty name
+    /// removes the binding.
     BindToDevice,
     Both,
     libc::SOL_SOCKET,
@@ -987,10 +1011,12 @@ sockopt_impl!(
     libc::SO_ACCEPTFILTER,
     libc::accept_filter_arg
 );
-#[cfg(target_os = "linux")]
+#[cfg(linux_android)]
 sockopt_impl!(
     /// Set the mark for each packet sent through this socket (similar to the
     /// netfilter MARK target but socket-based).
+    ///
+    /// Setting the mark requires the `CAP_NET_ADMIN` capability.
     Mark,
     Both,
     libc::SOL_SOCKET,
@@ -1013,12 +1039,27 @@ sockopt_impl!(
     #[cfg_attr(docsrs, doc(cfg(feature = "net")))]
     /// This option allows the caller to set the TCP congestion control
     /// algorithm to be used,  on a per-socket basis.
+    ///
+    /// Algorithm names are limited to `TCP_CA_NAME_MAX - 1` bytes.
     TcpCongestion,
     Both,
     libc::IPPROTO_TCP,
     libc::TCP_CONGESTION,
     OsString<[u8; TCP_CA_NAME_MAX]>
 );
+#[cfg(target_os = "linux")]
+#[cfg(feature = "net")]
+sockopt_impl!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "net")))]
+    /// Get information about the state of a TCP connection, such as its
+    /// round trip time and congestion window.
+    TcpInfo,
+    GetOnly,
+    libc::IPPROTO_TCP,
+    libc::TCP_INFO,
+    super::TcpInfo,
+    GetTcpInfo
+);
 #[cfg(any(linux_android, apple_targets, target_os = "netbsd"))]
 #[cfg(feature = "net")]
 sockopt_impl!(
@@ -1126,6 +1167,18 @@ sockopt_impl!(
     libc::SO_TXTIME,
     libc::sock_txtime
 );
+#[cfg(target_os = "linux")]
+sockopt_impl!(
+    /// Allows sending with
+    /// [`MSG_ZEROCOPY`](crate::sys::socket::MsgFlags::MSG_ZEROCOPY).
+    ///
+    /// [Further reading](https://docs.kernel.org/networking/msg_zerocopy.html)
+    ZeroCopy,
+    Both,
+    libc::SOL_SOCKET,
+    libc::SO_ZEROCOPY,
+    bool
+);
 #[cfg(any(linux_android, target_os = "fuchsia"))]
 sockopt_impl!(
     /// Indicates that an unsigned 32-bit value ancillary message (cmsg) should
@@ -1516,6 +1569,10 @@ pub trait Set<'a, T> {
     /// Returns length of the stored value. This pointer will be passed to the system's
     /// `setsockopt` call (`man 3p setsockopt`, argument `option_len`).
     fn ffi_len(&self) -> socklen_t;
+    /// Checks the stored value before it is passed to `setsockopt`.
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// Getter for an arbitrary `struct`.
@@ -1791,22 +1848,32 @@ impl<T: AsMut<[u8]>> Get<OsString> for GetOsString<T> {
     }
 }
 
-/// Setter for a `OsString` value.
+/// Setter for a `OsString` value, which the kernel copies into a buffer of `N`
+/// bytes including the terminating NUL.
+///
+/// Longer values fail with `EINVAL` rather than being silently truncated.
 // Hide the docs, because it's an implementation detail of `sockopt_impl!`
 #[doc(hidden)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct SetOsString<'a> {
+pub struct SetOsString<'a, const N: usize> {
     val: &'a OsStr,
 }
 
 #[cfg(any(target_os = "freebsd", linux_android))]
-impl<'a> Set<'a, OsString> for SetOsString<'a> {
-    fn new(val: &OsString) -> SetOsString {
+impl<'a, const N: usize> Set<'a, OsString> for SetOsString<'a, N> {
+    fn new(val: &OsString) -> SetOsString<N> {
         SetOsString {
             val: val.as_os_str(),
         }
     }
 
+    fn validate(&self) -> Result<()> {
+        if self.val.len() >= N {
+            return Err(Errno::EINVAL);
+        }
+        Ok(())
+    }
+
     fn ffi_ptr(&self) -> *const c_void {
         self.val.as_bytes().as_ptr().cast()
     }
@@ -1816,6 +1883,45 @@ impl<'a> Set<'a, OsString> for SetOsString<'a> {
     }
 }
 
+/// Getter for a [`TcpInfo`](super::TcpInfo) value.
+///
+/// `struct tcp_info` grows with new kernel versions, so older kernels may
+/// fill in fewer bytes than we ask for.  The tail is left zeroed.
+#[cfg(all(target_os = "linux", feature = "net"))]
+// Hide the docs, because it's an implementation detail of `sockopt_impl!`
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct GetTcpInfo {
+    len: socklen_t,
+    val: MaybeUninit<libc::tcp_info>,
+}
+
+#[cfg(all(target_os = "linux", feature = "net"))]
+impl Get<super::TcpInfo> for GetTcpInfo {
+    fn uninit() -> Self {
+        GetTcpInfo {
+            len: mem::size_of::<libc::tcp_info>() as socklen_t,
+            val: MaybeUninit::zeroed(),
+        }
+    }
+
+    fn ffi_ptr(&mut self) -> *mut c_void {
+        self.val.as_mut_ptr().cast()
+    }
+
+    fn ffi_len(&mut self) -> *mut socklen_t {
+        &mut self.len
+    }
+
+    unsafe fn assume_init(self) -> super::TcpInfo {
+        assert!(
+            self.len as usize <= mem::size_of::<libc::tcp_info>(),
+            "invalid getsockopt implementation"
+        );
+        super::TcpInfo::from(unsafe { self.val.assume_init() })
+    }
+}
+
 /// Getter for a `CString` value.
 #[cfg(apple_targets)]
 struct GetCString<T: AsMut<[u8]>> {
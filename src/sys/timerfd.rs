@@ -212,18 +212,25 @@ impl TimerFd {
 
     /// Wait for the configured alarm to expire.
     ///
+    /// Returns the number of times the alarm expired since it was set or
+    /// since the last `wait`, which may be more than one for an interval
+    /// alarm.  If the timer was canceled by a clock change, it returns 0.
+    ///
+    /// This blocks until the alarm expires, unless the timer was created with
+    /// [`TFD_NONBLOCK`](TimerFlags::TFD_NONBLOCK), in which case it fails
+    /// with `EAGAIN` if the alarm has not expired yet.
+    ///
     /// Note: If the alarm is unset, then you will wait forever.
-    pub fn wait(&self) -> Result<()> {
-        while let Err(e) = read(&self.fd, &mut [0u8; 8]) {
-            if e == Errno::ECANCELED {
-                break;
-            }
-            if e != Errno::EINTR {
-                return Err(e);
+    pub fn wait(&self) -> Result<u64> {
+        let mut buf = [0u8; 8];
+        loop {
+            match read(&self.fd, &mut buf) {
+                Ok(_) => return Ok(u64::from_ne_bytes(buf)),
+                Err(Errno::ECANCELED) => return Ok(0),
+                Err(Errno::EINTR) => (),
+                Err(e) => return Err(e),
             }
         }
-
-        Ok(())
     }
 
 
@@ -107,6 +107,84 @@ pub fn preadv<Fd: AsFd>(
     Errno::result(res).map(|r| r as usize)
 }
 
+#[cfg(all(target_os = "linux", any(target_env = "gnu", target_env = "musl")))]
+libc_bitflags! {
+    /// Per-call flags for [`preadv2`] and [`pwritev2`].
+    pub struct RWFlag: c_int {
+        /// High priority request, polling if possible.
+        RWF_HIPRI;
+        /// Per-write equivalent of `O_DSYNC`.
+        RWF_DSYNC;
+        /// Per-write equivalent of `O_SYNC`.
+        RWF_SYNC;
+        /// Fail with `EAGAIN` instead of blocking when the data is not
+        /// immediately available.
+        RWF_NOWAIT;
+        /// Per-write equivalent of `O_APPEND`.  The offset is ignored and the
+        /// data is written at the end of the file.
+        RWF_APPEND;
+    }
+}
+
+/// Write to `fd` from buffers in `iov`, like [`pwritev`] but with per-call
+/// `flags`.
+///
+/// If `offset` is `None`, the file's own offset is used and updated, as with
+/// [`writev`].
+///
+/// See also [pwritev2(2)](https://man7.org/linux/man-pages/man2/pwritev2.2.html)
+#[cfg(all(target_os = "linux", any(target_env = "gnu", target_env = "musl")))]
+pub fn pwritev2<Fd: AsFd>(
+    fd: Fd,
+    iov: &[IoSlice<'_>],
+    offset: Option<off_t>,
+    flags: RWFlag,
+) -> Result<usize> {
+    // SAFETY: same as in writev()
+    let res = unsafe {
+        libc::pwritev2(
+            fd.as_fd().as_raw_fd(),
+            iov.as_ptr().cast(),
+            iov.len() as c_int,
+            offset.unwrap_or(-1),
+            flags.bits(),
+        )
+    };
+
+    Errno::result(res).map(|r| r as usize)
+}
+
+/// Read from `fd` filling buffers in `iov`, like [`preadv`] but with per-call
+/// `flags`.
+///
+/// If `offset` is `None`, the file's own offset is used and updated, as with
+/// [`readv`].
+///
+/// See also [preadv2(2)](https://man7.org/linux/man-pages/man2/preadv2.2.html)
+// Clippy doesn't know that we need to pass iov mutably only because the
+// mutation happens after converting iov to a pointer
+#[allow(clippy::needless_pass_by_ref_mut)]
+#[cfg(all(target_os = "linux", any(target_env = "gnu", target_env = "musl")))]
+pub fn preadv2<Fd: AsFd>(
+    fd: Fd,
+    iov: &mut [IoSliceMut<'_>],
+    offset: Option<off_t>,
+    flags: RWFlag,
+) -> Result<usize> {
+    // SAFETY: same as in readv()
+    let res = unsafe {
+        libc::preadv2(
+            fd.as_fd().as_raw_fd(),
+            iov.as_ptr().cast(),
+            iov.len() as c_int,
+            offset.unwrap_or(-1),
+            flags.bits(),
+        )
+    };
+
+    Errno::result(res).map(|r| r as usize)
+}
+
 /// Low-level write to a file, with specified offset.
 ///
 /// See also [pwrite(2)](https://pubs.opengroup.org/onlinepubs/9699919799/functions/pwrite.html)
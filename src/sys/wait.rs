@@ -315,6 +315,23 @@ pub fn waitpid<P: Into<Option<Pid>>>(
     }
 }
 
+/// Check whether a child process has changed status, without blocking.
+///
+/// This is [`waitpid`] with `WNOHANG` added to `flags`.  Rather than
+/// returning [`WaitStatus::StillAlive`] when no matching child has changed
+/// status yet, it returns `Ok(None)`.
+///
+/// See also [waitpid(2)](https://pubs.opengroup.org/onlinepubs/9699919799/functions/waitpid.html)
+pub fn try_wait<P: Into<Option<Pid>>>(
+    pid: P,
+    flags: WaitPidFlag,
+) -> Result<Option<WaitStatus>> {
+    match waitpid(pid, Some(flags | WaitPidFlag::WNOHANG))? {
+        WaitStatus::StillAlive => Ok(None),
+        status => Ok(Some(status)),
+    }
+}
+
 /// Wait for any child process to change status or a signal is received.
 ///
 /// See also [wait(2)](https://pubs.opengroup.org/onlinepubs/9699919799/functions/wait.html)
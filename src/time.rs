@@ -254,6 +254,10 @@ libc_bitflags! {
 /// a signal is caught by a signal-catching function, or a signal causes the process to terminate,
 /// this sleep is interrrupted.
 ///
+/// Because an absolute deadline does not move, a sleep with `TIMER_ABSTIME`
+/// that fails with `EINTR` can simply be retried with the same `request`,
+/// without accumulating drift.
+///
 /// see also [man 3 clock_nanosleep](https://pubs.opengroup.org/onlinepubs/009695399/functions/clock_nanosleep.html)
 #[cfg(any(
     linux_android,
@@ -1,6 +1,6 @@
 //! Safe wrappers around functions found in libc "unistd.h" header
 
-use crate::errno::Errno;
+use crate::errno::{errno_clearing_call, Errno};
 
 #[cfg(not(target_os = "redox"))]
 #[cfg(feature = "fs")]
@@ -2497,19 +2497,9 @@ pub enum PathconfVar {
 pub fn fpathconf<F: std::os::fd::AsFd>(fd: F, var: PathconfVar) -> Result<Option<c_long>> {
     use std::os::fd::AsRawFd;
 
-    let raw = unsafe {
-        Errno::clear();
+    errno_clearing_call(|| unsafe {
         libc::fpathconf(fd.as_fd().as_raw_fd(), var as c_int)
-    };
-    if raw == -1 {
-        if Errno::last_raw() == 0 {
-            Ok(None)
-        } else {
-            Err(Errno::last())
-        }
-    } else {
-        Ok(Some(raw))
-    }
+    })
 }
 
 /// Get path-dependent configurable system variables (see
@@ -2537,19 +2527,11 @@ pub fn pathconf<P: ?Sized + NixPath>(
     path: &P,
     var: PathconfVar,
 ) -> Result<Option<c_long>> {
-    let raw = path.with_nix_path(|cstr| unsafe {
-        Errno::clear();
-        libc::pathconf(cstr.as_ptr(), var as c_int)
-    })?;
-    if raw == -1 {
-        if Errno::last_raw() == 0 {
-            Ok(None)
-        } else {
-            Err(Errno::last())
-        }
-    } else {
-        Ok(Some(raw))
-    }
+    path.with_nix_path(|cstr| {
+        errno_clearing_call(|| unsafe {
+            libc::pathconf(cstr.as_ptr(), var as c_int)
+        })
+    })?
 }
 }
 
@@ -3164,19 +3146,55 @@ pub enum SysconfVar {
 ///     unsupported (for option variables)
 /// - `Err(x)`: an error occurred
 pub fn sysconf(var: SysconfVar) -> Result<Option<c_long>> {
-    let raw = unsafe {
-        Errno::clear();
-        libc::sysconf(var as c_int)
-    };
-    if raw == -1 {
-        if Errno::last_raw() == 0 {
-            Ok(None)
-        } else {
-            Err(Errno::last())
-        }
-    } else {
-        Ok(Some(raw))
+    errno_clearing_call(|| unsafe { libc::sysconf(var as c_int) })
+}
+
+/// Get a configurable system variable, falling back to `default`.
+///
+/// Like [`sysconf`], but returns `default` if the variable has no limit, is
+/// unsupported, or can't be queried.
+pub fn sysconf_or(var: SysconfVar, default: c_long) -> c_long {
+    sysconf(var).ok().flatten().unwrap_or(default)
+}
+
+/// One greater than the largest file descriptor the process may open.
+///
+/// If [`SysconfVar::OPEN_MAX`] can't be queried or has no limit, this falls
+/// back to the soft `RLIMIT_NOFILE` resource limit, and to the POSIX minimum
+/// of 20 if that can't be queried either.  `usize::MAX` means there is no
+/// limit.
+pub fn open_max() -> usize {
+    if let Ok(Some(open_max)) = sysconf(SysconfVar::OPEN_MAX) {
+        return open_max as usize;
     }
+    let mut rlim = mem::MaybeUninit::<libc::rlimit>::uninit();
+    if unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, rlim.as_mut_ptr()) } == 0
+    {
+        let soft_limit = unsafe { rlim.assume_init() }.rlim_cur;
+        return usize::try_from(soft_limit).unwrap_or(usize::MAX);
+    }
+    // _POSIX_OPEN_MAX
+    20
+}
+
+/// The maximum length, in bytes, of the arguments and environment passed to
+/// the exec functions.
+///
+/// Falls back to the POSIX minimum of 4096 if [`SysconfVar::ARG_MAX`] can't be
+/// queried or has no limit.
+pub fn arg_max() -> usize {
+    // _POSIX_ARG_MAX
+    sysconf_or(SysconfVar::ARG_MAX, 4096) as usize
+}
+
+/// The number of clock ticks per second, as used by `times(2)` and `/proc`.
+///
+/// This is almost always 100.  That value is also returned in the unlikely
+/// case it can't be queried.
+///
+/// See [`SysconfVar::CLK_TCK`].
+pub fn clk_tck() -> usize {
+    sysconf_or(SysconfVar::CLK_TCK, 100) as usize
 }
 }
 
@@ -3949,17 +3967,45 @@ pub fn ttyname<F: std::os::fd::AsFd>(fd: F) -> Result<PathBuf> {
     #[cfg(target_os = "hurd")]
     const PATH_MAX: usize = 1024; // Hurd does not define a hard limit, so try a guess first
     let mut buf = vec![0_u8; PATH_MAX];
-    let c_buf = buf.as_mut_ptr().cast();
 
-    let ret = unsafe { libc::ttyname_r(fd.as_fd().as_raw_fd(), c_buf, buf.len()) };
-    if ret != 0 {
-        return Err(Errno::from_raw(ret));
+    loop {
+        let c_buf = buf.as_mut_ptr().cast();
+        let ret = unsafe { libc::ttyname_r(fd.as_fd().as_raw_fd(), c_buf, buf.len()) };
+        match ret {
+            0 => break,
+            // Only possible when PATH_MAX was a guess; grow the buffer and retry.
+            libc::ERANGE if cfg!(target_os = "hurd") => {
+                let len = buf.len() * 2;
+                buf.resize(len, 0);
+            }
+            _ => return Err(Errno::from_raw(ret)),
+        }
     }
 
     CStr::from_bytes_until_nul(&buf[..])
         .map(|s| OsStr::from_bytes(s.to_bytes()).into())
         .map_err(|_| Errno::EINVAL)
 }
+
+/// Get the path of the controlling terminal of the current process
+/// (see [`ctermid(3)`](https://pubs.opengroup.org/onlinepubs/9699919799/functions/ctermid.html)).
+///
+/// The returned path, usually `/dev/tty`, is not guaranteed to be openable.
+/// It is empty if the path cannot be determined.
+#[cfg(any(
+    all(target_os = "linux", any(target_env = "gnu", target_env = "musl")),
+    target_os = "aix",
+    target_os = "haiku",
+    target_os = "hurd"
+))]
+pub fn ctermid() -> PathBuf {
+    // Passing our own buffer, rather than NULL, keeps this thread-safe.  Any
+    // buffer of at least L_ctermid bytes will do.
+    let mut buf = [0 as libc::c_char; 256];
+    let ptr = unsafe { libc::ctermid(buf.as_mut_ptr()) };
+    let name = unsafe { CStr::from_ptr(ptr) };
+    PathBuf::from(OsStr::from_bytes(name.to_bytes()))
+}
 }
 
 feature! {
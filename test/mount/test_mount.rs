@@ -2,6 +2,7 @@ use std::fs::{self, File};
 use std::io::{Read, Write};
 use std::os::unix::fs::OpenOptionsExt;
 use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
 use std::process::Command;
 
 use libc::{EACCES, EROFS};
@@ -187,3 +188,158 @@ fn test_mount_bind() {
         .unwrap_or_else(|e| panic!("read failed: {e}"));
     assert_eq!(buf, SCRIPT_CONTENTS);
 }
+
+#[test]
+fn test_fsmount_tmpfs() {
+    use nix::errno::Errno;
+    use nix::fcntl::AT_FDCWD;
+    use nix::mount::{
+        fsconfig, fsmount, fsopen, move_mount, FsConfigCmd, FsMountFlags,
+        FsOpenFlags, MountAttrFlags, MoveMountFlags,
+    };
+
+    use std::ffi::CStr;
+
+    require_capability!("test_fsmount_tmpfs", CAP_SYS_ADMIN);
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let fs_fd = match fsopen(
+        CStr::from_bytes_with_nul(b"tmpfs\0").unwrap(),
+        FsOpenFlags::FSOPEN_CLOEXEC,
+    ) {
+        Err(Errno::ENOSYS) => skip!("fsopen is not supported. Skipping test."),
+        res => res.unwrap(),
+    };
+    fsconfig(
+        &fs_fd,
+        FsConfigCmd::FSCONFIG_SET_STRING,
+        Some(CStr::from_bytes_with_nul(b"size\0").unwrap()),
+        Some(CStr::from_bytes_with_nul(b"1m\0").unwrap()),
+        0,
+    )
+    .unwrap();
+    fsconfig(&fs_fd, FsConfigCmd::FSCONFIG_CMD_CREATE, None, None, 0).unwrap();
+    let mnt_fd = fsmount(
+        &fs_fd,
+        FsMountFlags::FSMOUNT_CLOEXEC,
+        MountAttrFlags::MOUNT_ATTR_RDONLY,
+    )
+    .unwrap();
+    move_mount(
+        &mnt_fd,
+        "",
+        AT_FDCWD,
+        tempdir.path(),
+        MoveMountFlags::MOVE_MOUNT_F_EMPTY_PATH,
+    )
+    .unwrap();
+    // Open mount file descriptors keep the mount busy
+    drop(mnt_fd);
+    drop(fs_fd);
+
+    // EROFS: Read-only file system
+    assert_eq!(
+        EROFS,
+        File::create(tempdir.path().join("test"))
+            .unwrap_err()
+            .raw_os_error()
+            .unwrap()
+    );
+
+    umount(tempdir.path()).unwrap_or_else(|e| panic!("umount failed: {e}"));
+}
+
+#[test]
+fn test_umount2_detach_busy() {
+    use nix::errno::Errno;
+    use nix::mount::{umount2, MntFlags};
+
+    require_capability!("test_umount2_detach_busy", CAP_SYS_ADMIN);
+    let tempdir = tempfile::tempdir().unwrap();
+
+    mount(
+        NONE,
+        tempdir.path(),
+        Some(b"tmpfs".as_ref()),
+        MsFlags::empty(),
+        NONE,
+    )
+    .unwrap_or_else(|e| panic!("mount failed: {e}"));
+
+    let test_path = tempdir.path().join("test");
+    let mut busy = File::create(&test_path).unwrap();
+
+    assert_eq!(umount(tempdir.path()), Err(Errno::EBUSY));
+    umount2(tempdir.path(), MntFlags::MNT_DETACH)
+        .unwrap_or_else(|e| panic!("umount2 failed: {e}"));
+
+    // The mount point is gone, but the open file still refers to the tmpfs.
+    assert!(!test_path.exists());
+    busy.write_all(SCRIPT_CONTENTS).unwrap();
+}
+
+#[test]
+fn test_bind_mount_private() {
+    use nix::mount::{bind_mount, make_private};
+
+    require_capability!("test_bind_mount_private", CAP_SYS_ADMIN);
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let file_name = "test";
+    File::create(source.path().join(file_name)).unwrap();
+
+    bind_mount(source.path(), target.path(), false)
+        .unwrap_or_else(|e| panic!("bind_mount failed: {e}"));
+    make_private(target.path(), false)
+        .unwrap_or_else(|e| panic!("make_private failed: {e}"));
+
+    assert!(target.path().join(file_name).exists());
+    // A private mount has no "shared:N" or "master:N" peer group in its
+    // optional fields.
+    let line = mountinfo_line(target.path());
+    assert!(!line.contains("shared:"), "{line}");
+    assert!(!line.contains("master:"), "{line}");
+
+    // wait for child processes to prevent EBUSY
+    let _m = FORK_MTX.lock();
+    umount(target.path()).unwrap_or_else(|e| panic!("umount failed: {e}"));
+}
+
+#[test]
+fn test_set_propagation_unbindable() {
+    use nix::errno::Errno;
+    use nix::mount::{bind_mount, set_propagation, MountPropagation};
+
+    require_capability!("test_set_propagation_unbindable", CAP_SYS_ADMIN);
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let other = tempfile::tempdir().unwrap();
+
+    bind_mount(source.path(), target.path(), false)
+        .unwrap_or_else(|e| panic!("bind_mount failed: {e}"));
+    set_propagation(target.path(), MountPropagation::Unbindable, false)
+        .unwrap_or_else(|e| panic!("set_propagation failed: {e}"));
+
+    let line = mountinfo_line(target.path());
+    assert!(line.contains(" unbindable "), "{line}");
+    // An unbindable mount can't be the source of a bind mount.
+    assert_eq!(
+        bind_mount(target.path(), other.path(), false),
+        Err(Errno::EINVAL)
+    );
+
+    // wait for child processes to prevent EBUSY
+    let _m = FORK_MTX.lock();
+    umount(target.path()).unwrap_or_else(|e| panic!("umount failed: {e}"));
+}
+
+/// Return the last line of /proc/self/mountinfo whose mount point is `path`.
+fn mountinfo_line(path: &Path) -> String {
+    let mountinfo = fs::read_to_string("/proc/self/mountinfo").unwrap();
+    let path = path.to_str().unwrap();
+    mountinfo
+        .lines()
+        .rfind(|line| line.split(' ').nth(4) == Some(path))
+        .unwrap()
+        .to_owned()
+}
@@ -12,6 +12,8 @@ mod test_signal;
     target_os = "netbsd"
 ))]
 mod test_aio;
+#[cfg(all(target_os = "linux", feature = "feature"))]
+mod test_auxv;
 #[cfg(not(any(
     target_os = "redox",
     target_os = "fuchsia",
@@ -43,11 +45,19 @@ mod test_wait;
 
 #[cfg(linux_android)]
 mod test_epoll;
+#[cfg(any(linux_android, target_os = "freebsd"))]
+mod test_eventfd;
 #[cfg(target_os = "linux")]
 mod test_fanotify;
 #[cfg(target_os = "linux")]
 mod test_inotify;
+#[cfg(all(target_os = "linux", feature = "process"))]
+mod test_ioprio;
+#[cfg(target_os = "linux")]
+mod test_personality;
 mod test_pthread;
+#[cfg(all(any(target_os = "linux", bsd), feature = "random"))]
+mod test_random;
 
 #[cfg(any(linux_android, freebsdlike, netbsdlike, apple_targets))]
 mod test_ptrace;
@@ -0,0 +1,15 @@
+use nix::sys::auxv::{getauxval, AuxvType};
+use nix::unistd::{sysconf, SysconfVar};
+
+#[test]
+fn test_getauxval_pagesz() {
+    let page_size = getauxval(AuxvType::AT_PAGESZ).unwrap();
+    let expected = sysconf(SysconfVar::PAGE_SIZE).unwrap().unwrap();
+    assert_eq!(page_size, expected as u64);
+}
+
+#[test]
+fn test_getauxval_random() {
+    // The kernel always provides AT_RANDOM, as a pointer to 16 bytes.
+    assert_ne!(getauxval(AuxvType::AT_RANDOM).unwrap(), 0);
+}
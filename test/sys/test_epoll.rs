@@ -24,3 +24,35 @@ pub fn test_epoll_ctl() {
     epoll_ctl(efd, EpollOp::EpollCtlAdd, 1, &mut event).unwrap();
     epoll_ctl(efd, EpollOp::EpollCtlDel, 1, None).unwrap();
 }
+
+#[test]
+pub fn test_epoll_edge_triggered() {
+    use nix::sys::epoll::Epoll;
+    use nix::unistd::{pipe, write};
+
+    const TOKEN: u64 = 42;
+
+    let epoll = Epoll::new(EpollCreateFlags::EPOLL_CLOEXEC).unwrap();
+    let (r, w) = pipe().unwrap();
+    epoll
+        .add(
+            &r,
+            EpollEvent::new(EpollFlags::EPOLLIN | EpollFlags::EPOLLET, TOKEN),
+        )
+        .unwrap();
+
+    write(&w, b"abc").unwrap();
+    let mut events = [EpollEvent::empty(); 2];
+    assert_eq!(epoll.wait(&mut events, 0u8).unwrap(), 1);
+    assert_eq!(events[0].data(), TOKEN);
+    assert!(events[0].events().contains(EpollFlags::EPOLLIN));
+
+    // The data was not read, but no new edge happened.
+    assert_eq!(epoll.wait(&mut events, 0u8).unwrap(), 0);
+
+    // New data is a new edge.
+    write(&w, b"def").unwrap();
+    assert_eq!(epoll.wait(&mut events, 0u8).unwrap(), 1);
+
+    epoll.delete(&r).unwrap();
+}
@@ -0,0 +1,30 @@
+use nix::errno::Errno;
+use nix::sys::eventfd::{EfdFlags, EventFd};
+
+#[test]
+fn test_read_write() {
+    let efd = EventFd::new().unwrap();
+
+    efd.write(3).unwrap();
+    assert_eq!(efd.read().unwrap(), 3);
+}
+
+#[test]
+fn test_initial_value() {
+    let efd = EventFd::from_value(5).unwrap();
+    efd.write(2).unwrap();
+    assert_eq!(efd.read().unwrap(), 7);
+}
+
+#[test]
+fn test_semaphore() {
+    let efd = EventFd::from_value_and_flags(
+        2,
+        EfdFlags::EFD_SEMAPHORE | EfdFlags::EFD_NONBLOCK,
+    )
+    .unwrap();
+
+    assert_eq!(efd.read().unwrap(), 1);
+    assert_eq!(efd.read().unwrap(), 1);
+    assert_eq!(efd.read(), Err(Errno::EAGAIN));
+}
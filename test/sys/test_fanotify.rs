@@ -2,8 +2,8 @@ use crate::*;
 use nix::errno::Errno;
 use nix::fcntl::AT_FDCWD;
 use nix::sys::fanotify::{
-    EventFFlags, Fanotify, FanotifyResponse, InitFlags, MarkFlags, MaskFlags,
-    Response,
+    EventFFlags, Fanotify, FanotifyResponse, FidInfoType, InitFlags, MarkFlags,
+    MaskFlags, Response,
 };
 use std::fs::{read_link, read_to_string, File, OpenOptions};
 use std::io::ErrorKind;
@@ -19,6 +19,7 @@ pub fn test_fanotify() {
     test_fanotify_notifications();
     test_fanotify_responses();
     test_fanotify_overflow();
+    test_fanotify_fid();
 }
 
 fn test_fanotify_notifications() {
@@ -218,3 +219,42 @@ fn test_fanotify_overflow() {
     assert_eq!(n, max_events + 1);
     assert_eq!(last_event, Some(MaskFlags::FAN_Q_OVERFLOW));
 }
+
+fn test_fanotify_fid() {
+    let group = match Fanotify::init(
+        InitFlags::FAN_CLASS_NOTIF | InitFlags::FAN_REPORT_DFID_NAME,
+        EventFFlags::O_RDONLY,
+    ) {
+        Err(Errno::EINVAL) => {
+            skip!("FAN_REPORT_DFID_NAME is not supported. Skipping test.")
+        }
+        res => res.unwrap(),
+    };
+    let tempdir = tempfile::tempdir().unwrap();
+
+    match group.mark(
+        MarkFlags::FAN_MARK_ADD,
+        MaskFlags::FAN_CREATE | MaskFlags::FAN_ONDIR,
+        AT_FDCWD,
+        Some(tempdir.path()),
+    ) {
+        Err(Errno::EOPNOTSUPP | Errno::ENODEV | Errno::EXDEV) => {
+            skip!("File handles are not supported. Skipping test.")
+        }
+        res => res.unwrap(),
+    }
+
+    File::create(tempdir.path().join("test")).unwrap();
+
+    let mut events = group.read_fid_events().unwrap();
+    assert_eq!(events.len(), 1, "should have read exactly one event");
+    let event = events.pop().unwrap();
+    assert_eq!(event.mask(), MaskFlags::FAN_CREATE);
+    assert_eq!(
+        event.info_type(),
+        FidInfoType::FAN_EVENT_INFO_TYPE_DFID_NAME
+    );
+    // struct file_handle has an 8-byte header before the handle itself.
+    assert!(event.handle().len() > 8);
+    assert_eq!(event.name(), Some("test".as_ref()));
+}
@@ -1,7 +1,10 @@
 use nix::errno::Errno;
-use nix::sys::inotify::{AddWatchFlags, InitFlags, Inotify};
+use nix::sys::inotify::{
+    AddWatchFlags, InitFlags, Inotify, RenameTracker, TrackedEvent,
+};
 use std::ffi::OsString;
 use std::fs::{rename, File};
+use std::time::Duration;
 
 #[test]
 pub fn test_inotify() {
@@ -63,3 +66,42 @@ pub fn test_inotify_multi_events() {
 
     assert_eq!(events[3].cookie, events[4].cookie);
 }
+
+#[test]
+pub fn test_inotify_rename_tracker() {
+    let instance = Inotify::init(InitFlags::IN_NONBLOCK).unwrap();
+    let tempdir = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    File::create(tempdir.path().join("test")).unwrap();
+
+    instance
+        .add_watch(tempdir.path(), AddWatchFlags::IN_MOVE)
+        .unwrap();
+    let mut tracker = RenameTracker::new(Duration::ZERO);
+
+    rename(tempdir.path().join("test"), tempdir.path().join("test2")).unwrap();
+    let mut events = tracker.process(instance.read_events().unwrap());
+    assert_eq!(events.len(), 1);
+    match events.pop().unwrap() {
+        TrackedEvent::Renamed { from, to } => {
+            assert_eq!(from.name, Some(OsString::from("test")));
+            assert_eq!(to.name, Some(OsString::from("test2")));
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+
+    // Moving out of the watched directory leaves an unmatched IN_MOVED_FROM,
+    // which is held back until it expires.
+    rename(tempdir.path().join("test2"), outside.path().join("test2")).unwrap();
+    let events = tracker.process(instance.read_events().unwrap());
+    assert!(events.is_empty());
+    let mut events = tracker.flush_expired();
+    assert_eq!(events.len(), 1);
+    match events.pop().unwrap() {
+        TrackedEvent::Event(event) => {
+            assert_eq!(event.mask, AddWatchFlags::IN_MOVED_FROM);
+            assert_eq!(event.name, Some(OsString::from("test2")));
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+}
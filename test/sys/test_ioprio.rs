@@ -0,0 +1,11 @@
+use nix::sys::ioprio::{ioprio_get, ioprio_set, IoprioClass, IoprioWhich};
+
+#[test]
+fn test_ioprio_best_effort() {
+    // Only affects the calling thread, which is the test's own.
+    ioprio_set(IoprioWhich::Process, 0, IoprioClass::BestEffort, 7).unwrap();
+    assert_eq!(
+        ioprio_get(IoprioWhich::Process, 0).unwrap(),
+        (IoprioClass::BestEffort, 7)
+    );
+}
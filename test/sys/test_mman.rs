@@ -198,3 +198,68 @@ fn test_madv_wipeonfork() {
         }
     }
 }
+
+#[test]
+fn test_madvise_dontneed_willneed() {
+    use nix::sys::mman::{madvise, munmap, MmapAdvise};
+    use nix::unistd::{sysconf, SysconfVar};
+
+    let page_size = sysconf(SysconfVar::PAGE_SIZE).unwrap().unwrap() as usize;
+    unsafe {
+        let mem = mmap_anonymous(
+            None,
+            NonZeroUsize::new(page_size).unwrap(),
+            ProtFlags::PROT_READ | ProtFlags::PROT_WRITE,
+            MapFlags::MAP_PRIVATE,
+        )
+        .unwrap();
+        *mem.cast::<u8>().as_mut() = 0xff;
+
+        madvise(mem, page_size, MmapAdvise::MADV_DONTNEED).unwrap();
+        madvise(mem, page_size, MmapAdvise::MADV_WILLNEED).unwrap();
+        #[cfg(linux_android)]
+        // Private anonymous pages are zero-filled again after MADV_DONTNEED
+        assert_eq!(*mem.cast::<u8>().as_ref(), 0);
+
+        #[cfg(target_os = "linux")]
+        for advice in [MmapAdvise::MADV_COLD, MmapAdvise::MADV_PAGEOUT] {
+            match madvise(mem, page_size, advice) {
+                // Added in Linux 5.4
+                Err(nix::errno::Errno::EINVAL) => (),
+                res => res.unwrap(),
+            }
+        }
+
+        munmap(mem, page_size).unwrap();
+    }
+}
+
+#[test]
+#[cfg(all(target_os = "linux", any(target_env = "gnu", target_env = "musl")))]
+fn test_mlock2_onfault() {
+    use nix::errno::Errno;
+    use nix::sys::mman::{mlock2, munlock, munmap, Mlock2Flags};
+    use nix::unistd::{sysconf, SysconfVar};
+
+    let page_size = sysconf(SysconfVar::PAGE_SIZE).unwrap().unwrap() as usize;
+    unsafe {
+        let mem = mmap_anonymous(
+            None,
+            NonZeroUsize::new(page_size).unwrap(),
+            ProtFlags::PROT_READ | ProtFlags::PROT_WRITE,
+            MapFlags::MAP_PRIVATE,
+        )
+        .unwrap();
+
+        match mlock2(mem, page_size, Mlock2Flags::MLOCK_ONFAULT) {
+            // RLIMIT_MEMLOCK is too low
+            Err(Errno::EPERM | Errno::ENOMEM) => {
+                munmap(mem, page_size).unwrap();
+                skip!("mlock2 is not permitted. Skipping test.");
+            }
+            res => res.unwrap(),
+        }
+        munlock(mem, page_size).unwrap();
+        munmap(mem, page_size).unwrap();
+    }
+}
@@ -0,0 +1,33 @@
+use nix::sys::personality::{self, Persona};
+use nix::sys::wait::{waitpid, WaitStatus};
+use nix::unistd::{fork, ForkResult};
+
+#[test]
+fn test_set_addr_no_randomize() {
+    let _m = crate::FORK_MTX.lock();
+
+    // Change the personality in a child, so it doesn't leak into other tests.
+    match unsafe { fork() }.unwrap() {
+        ForkResult::Child => {
+            let toggled = (|| {
+                let orig = personality::get()?;
+                let prev = personality::set_addr_no_randomize(true)?;
+                let on = personality::get()?;
+                personality::set_addr_no_randomize(false)?;
+                let off = personality::get()?;
+                Ok::<_, nix::Error>(
+                    prev == orig
+                        && on.contains(Persona::ADDR_NO_RANDOMIZE)
+                        && !off.contains(Persona::ADDR_NO_RANDOMIZE)
+                        && on - Persona::ADDR_NO_RANDOMIZE
+                            == orig - Persona::ADDR_NO_RANDOMIZE,
+                )
+            })();
+            let code = if matches!(toggled, Ok(true)) { 0 } else { 1 };
+            unsafe { libc::_exit(code) };
+        }
+        ForkResult::Parent { child } => {
+            assert_eq!(waitpid(child, None), Ok(WaitStatus::Exited(child, 0)));
+        }
+    }
+}
@@ -21,9 +21,14 @@ mod test_prctl {
     fn test_get_set_dumpable() {
         let original = prctl::get_dumpable().unwrap();
 
-        prctl::set_dumpable(false).unwrap();
+        prctl::set_dumpable(prctl::Dumpable::NotDumpable).unwrap();
         let dumpable = prctl::get_dumpable().unwrap();
-        assert!(!dumpable);
+        assert_eq!(dumpable, prctl::Dumpable::NotDumpable);
+
+        assert_eq!(
+            prctl::set_dumpable(prctl::Dumpable::RootDumpable).unwrap_err(),
+            nix::errno::Errno::EINVAL
+        );
 
         prctl::set_dumpable(original).unwrap();
     }
@@ -39,6 +44,21 @@ mod test_prctl {
         prctl::set_keepcaps(original).unwrap();
     }
 
+    #[test]
+    fn test_capbset_read() {
+        use prctl::Capability::CAP_SYS_ADMIN;
+
+        let status = std::fs::read_to_string("/proc/self/status").unwrap();
+        let capbnd = status
+            .lines()
+            .find_map(|line| line.strip_prefix("CapBnd:"))
+            .map(|mask| u64::from_str_radix(mask.trim(), 16).unwrap())
+            .unwrap();
+        let expected = capbnd & (1 << CAP_SYS_ADMIN as u32) != 0;
+
+        assert_eq!(prctl::capbset_read(CAP_SYS_ADMIN).unwrap(), expected);
+    }
+
     #[test]
     fn test_get_set_clear_mce_kill() {
         use prctl::PrctlMCEKillPolicy::*;
@@ -89,11 +109,11 @@ mod test_prctl {
     #[cfg_attr(qemu, ignore)]
     #[test]
     fn test_get_set_timerslack() {
-        let original = prctl::get_timerslack().unwrap() as libc::c_ulong;
+        let original = prctl::get_timerslack().unwrap();
 
-        let slack = 60_000;
+        let slack = 100_000;
         prctl::set_timerslack(slack).unwrap();
-        let res = prctl::get_timerslack().unwrap() as libc::c_ulong;
+        let res = prctl::get_timerslack().unwrap();
         assert_eq!(slack, res);
 
         prctl::set_timerslack(original).unwrap();
@@ -109,6 +129,29 @@ mod test_prctl {
         prctl::task_perf_events_enable().unwrap();
     }
 
+    #[test]
+    fn test_set_mm() {
+        use nix::errno::Errno;
+
+        // arg_start is the 48th field of /proc/self/stat.  The fields after
+        // the parenthesized command name start from the 3rd.
+        let stat = std::fs::read_to_string("/proc/self/stat").unwrap();
+        let (_, fields) = stat.rsplit_once(')').unwrap();
+        let arg_start: u64 = fields
+            .split_whitespace()
+            .nth(48 - 3)
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        match prctl::set_mm(prctl::PrctlMmField::PR_SET_MM_ARG_START, arg_start)
+        {
+            // Requires CAP_SYS_RESOURCE
+            Err(Errno::EPERM) => (),
+            res => res.unwrap(),
+        }
+    }
+
     #[test]
     fn test_get_set_no_new_privs() {
         prctl::set_no_new_privs().unwrap();
@@ -128,6 +128,89 @@ fn test_ptrace_cont() {
     }
 }
 
+#[test]
+fn test_ptrace_cont_with_signal() {
+    use nix::sys::ptrace;
+    use nix::sys::signal::{raise, Signal};
+    use nix::sys::wait::{waitpid, WaitStatus};
+    use nix::unistd::fork;
+    use nix::unistd::ForkResult::*;
+
+    require_capability!("test_ptrace_cont_with_signal", CAP_SYS_PTRACE);
+
+    let _m = crate::FORK_MTX.lock();
+
+    // FIXME: qemu-user doesn't implement ptrace on all architectures
+    // and returns ENOSYS in this case.
+    let err = ptrace::attach(getpid()).unwrap_err();
+    if err == Errno::ENOSYS {
+        return;
+    }
+
+    match unsafe { fork() }.expect("Error: Fork Failed") {
+        Child => {
+            ptrace::traceme().unwrap();
+            raise(Signal::SIGSTOP).unwrap();
+            unsafe { libc::_exit(7) };
+        }
+        Parent { child } => {
+            assert_eq!(
+                waitpid(child, None),
+                Ok(WaitStatus::Stopped(child, Signal::SIGSTOP))
+            );
+            // Inject SIGCONT while single-stepping.  It has no handler, so
+            // the child just takes one more step.
+            #[cfg(linux_android)]
+            {
+                ptrace::step(child, Some(Signal::SIGCONT)).unwrap();
+                assert_eq!(
+                    waitpid(child, None),
+                    Ok(WaitStatus::Stopped(child, Signal::SIGTRAP))
+                );
+            }
+            // Resume with SIGCONT, and let the child run to completion.
+            ptrace::cont(child, Some(Signal::SIGCONT)).unwrap();
+            assert_eq!(waitpid(child, None), Ok(WaitStatus::Exited(child, 7)));
+        }
+    }
+}
+
+#[cfg(linux_android)]
+#[test]
+fn test_traced_process() {
+    use nix::sys::ptrace::TracedProcess;
+    use nix::sys::wait::{waitpid, WaitStatus};
+    use nix::unistd::fork;
+    use nix::unistd::ForkResult::*;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    require_capability!("test_traced_process", CAP_SYS_PTRACE);
+
+    let _m = crate::FORK_MTX.lock();
+
+    match unsafe { fork() }.expect("Error: Fork Failed") {
+        Child => {
+            sleep(Duration::from_millis(500));
+            unsafe { libc::_exit(3) };
+        }
+        Parent { child } => {
+            let traced = TracedProcess::attach(child).unwrap();
+            assert_eq!(traced.pid(), child);
+            #[cfg(all(
+                target_os = "linux",
+                target_arch = "x86_64",
+                any(target_env = "gnu", target_env = "musl")
+            ))]
+            assert_ne!(traced.getregs().unwrap().rsp, 0);
+            drop(traced);
+
+            // Once detached, the child finishes sleeping and exits normally.
+            assert_eq!(waitpid(child, None), Ok(WaitStatus::Exited(child, 3)));
+        }
+    }
+}
+
 #[cfg(target_os = "linux")]
 #[test]
 fn test_ptrace_interrupt() {
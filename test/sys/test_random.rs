@@ -0,0 +1,23 @@
+#[test]
+#[cfg(target_os = "linux")]
+fn test_getrandom() {
+    use nix::sys::random::{getrandom, GetRandomFlags};
+
+    let mut buf = [0u8; 32];
+    let n = getrandom(&mut buf, GetRandomFlags::empty()).unwrap();
+    assert_eq!(n, buf.len());
+    // The odds of 32 random bytes all being zero are negligible.
+    assert_ne!(buf, [0u8; 32]);
+}
+
+#[test]
+#[cfg(bsd)]
+fn test_arc4random_buf() {
+    use nix::sys::random::arc4random_buf;
+
+    let mut a = [0u8; 16];
+    let mut b = [0u8; 16];
+    arc4random_buf(&mut a);
+    arc4random_buf(&mut b);
+    assert_ne!(a, b);
+}
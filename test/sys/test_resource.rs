@@ -1,3 +1,4 @@
+use nix::sys::resource::{fd_limit, raise_fd_limit_to_max};
 use nix::sys::resource::{getrlimit, setrlimit, Resource};
 use nix::sys::resource::{getrusage, UsageWho};
 
@@ -11,6 +12,7 @@ use nix::sys::resource::{getrusage, UsageWho};
 /// been updated.
 #[test]
 pub fn test_resource_limits_nofile() {
+    let _m = crate::NOFILE_MTX.lock();
     let (mut soft_limit, hard_limit) =
         getrlimit(Resource::RLIMIT_NOFILE).unwrap();
 
@@ -22,6 +24,23 @@ pub fn test_resource_limits_nofile() {
     assert_eq!(new_soft_limit, soft_limit);
 }
 
+#[test]
+pub fn test_raise_fd_limit_to_max() {
+    let _m = crate::NOFILE_MTX.lock();
+    let (orig_soft_limit, hard_limit) = fd_limit().unwrap();
+
+    let soft_limit = raise_fd_limit_to_max().unwrap();
+    let raised = fd_limit().unwrap();
+    // Restore the limit before asserting, so later tests don't inherit it
+    setrlimit(Resource::RLIMIT_NOFILE, orig_soft_limit, hard_limit).unwrap();
+
+    #[cfg(not(apple_targets))]
+    assert_eq!(soft_limit, hard_limit);
+    #[cfg(apple_targets)]
+    assert!(soft_limit <= hard_limit);
+    assert_eq!(raised, (soft_limit, hard_limit));
+}
+
 #[test]
 pub fn test_self_cpu_time() {
     // Make sure some CPU time is used.
@@ -18,6 +18,35 @@ fn test_killpg_none() {
         .expect("Should be able to send signal to my process group.");
 }
 
+#[test]
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox")))]
+fn test_killpg_child_group() {
+    use nix::sys::wait::{waitpid, WaitStatus};
+
+    let _m = crate::FORK_MTX.lock();
+
+    // Safe: The child only calls setpgid, pause and _exit, which are
+    // async-signal-safe.
+    match unsafe { fork() }.expect("Error: Fork Failed") {
+        ForkResult::Child => {
+            setpgid(Pid::from_raw(0), Pid::from_raw(0)).ok();
+            pause();
+            unsafe { libc::_exit(123) }
+        }
+        ForkResult::Parent { child } => {
+            // Also set the group here, so that it exists before killpg
+            // regardless of which process runs first.
+            setpgid(child, child).unwrap();
+            assert_ne!(getpgid(Some(child)).unwrap(), getpgrp());
+            killpg(child, SIGTERM).unwrap();
+            assert_eq!(
+                waitpid(child, None),
+                Ok(WaitStatus::Signaled(child, SIGTERM, false))
+            );
+        }
+    }
+}
+
 #[test]
 fn test_old_sigaction_flags() {
     let _m = crate::SIGNAL_MTX.lock();
@@ -34,6 +63,33 @@ fn test_old_sigaction_flags() {
     let _flags = oact.flags();
 }
 
+#[test]
+fn test_sigaction_scoped() {
+    let _m = crate::SIGNAL_MTX.lock();
+    static HANDLED: AtomicBool = AtomicBool::new(false);
+    extern "C" fn handler(_: ::libc::c_int) {
+        HANDLED.store(true, Ordering::SeqCst);
+    }
+
+    let dfl =
+        SigAction::new(SigHandler::SigDfl, SaFlags::empty(), SigSet::empty());
+    let orig = unsafe { sigaction(SIGUSR2, &dfl) }.unwrap();
+    {
+        let act = SigAction::new(
+            SigHandler::Handler(handler),
+            SaFlags::empty(),
+            SigSet::empty(),
+        );
+        let guard = unsafe { sigaction_scoped(SIGUSR2, &act) }.unwrap();
+        assert_eq!(guard.old_action().handler(), SigHandler::SigDfl);
+        raise(SIGUSR2).unwrap();
+        assert!(HANDLED.load(Ordering::SeqCst));
+    }
+    // The guard has reinstalled the default disposition.
+    let cur = unsafe { sigaction(SIGUSR2, &orig) }.unwrap();
+    assert_eq!(cur.handler(), SigHandler::SigDfl);
+}
+
 #[test]
 fn test_sigprocmask_noop() {
     sigprocmask(SigmaskHow::SIG_BLOCK, None, None)
@@ -156,6 +212,43 @@ fn test_contains() {
     assert!(all.contains(SIGUSR2));
 }
 
+#[test]
+fn test_union_intersection() {
+    let usr = SIGUSR1 | SIGUSR2;
+    let other = SIGUSR2 | SIGTERM;
+
+    assert_eq!(usr.iter().collect::<Vec<_>>(), [SIGUSR1, SIGUSR2]);
+    assert_eq!(usr.union(&other), SIGUSR1 | SIGUSR2 | SIGTERM);
+    assert_eq!(usr.intersection(&other), SigSet::from(SIGUSR2));
+    assert_eq!(usr.intersection(&SigSet::empty()), SigSet::empty());
+}
+
+#[test]
+#[cfg(linux_android)]
+fn test_union_intersection_realtime() {
+    let rt = |signum| {
+        let mut sigset = std::mem::MaybeUninit::uninit();
+        unsafe {
+            libc::sigemptyset(sigset.as_mut_ptr());
+            libc::sigaddset(sigset.as_mut_ptr(), signum);
+            SigSet::from_sigset_t_unchecked(sigset.assume_init())
+        }
+    };
+    let is_member = |set: &SigSet, signum| unsafe {
+        libc::sigismember(set.as_ref(), signum) == 1
+    };
+    let (min, max) = (Signal::SIGRTMIN(), Signal::SIGRTMAX());
+
+    let union = rt(min).union(&rt(max));
+    assert!(is_member(&union, min));
+    assert!(is_member(&union, max));
+    assert!(is_member(&(rt(min) | SigSet::from(SIGUSR1)), min));
+
+    let intersection = union.intersection(&rt(max));
+    assert!(!is_member(&intersection, min));
+    assert!(is_member(&intersection, max));
+}
+
 #[test]
 fn test_clear() {
     let mut set = SigSet::all();
@@ -173,12 +266,36 @@ fn test_from_str_round_trips() {
     }
 }
 
+#[test]
+#[cfg(linux_android)]
+fn test_realtime_signals() {
+    let (min, max) = (Signal::SIGRTMIN(), Signal::SIGRTMAX());
+    assert!(min <= max);
+    assert!(Signal::realtime_signals().count() > 0);
+    for signal in Signal::realtime_signals() {
+        assert!((min..=max).contains(&signal));
+        // Real-time signals are not among the standard signals.
+        assert!(Signal::try_from(signal).is_err());
+    }
+}
+
+#[test]
+fn test_from_str_lenient() {
+    assert_eq!("SIGKILL".parse::<Signal>(), Ok(Signal::SIGKILL));
+    assert_eq!("kill".parse::<Signal>(), Ok(Signal::SIGKILL));
+    assert_eq!("SigKill".parse::<Signal>(), Ok(Signal::SIGKILL));
+    assert_eq!("9".parse::<Signal>(), Ok(Signal::SIGKILL));
+    assert_eq!("term".parse::<Signal>(), Ok(Signal::SIGTERM));
+}
+
 #[test]
 fn test_from_str_invalid_value() {
     let errval = Err(Errno::EINVAL);
     assert_eq!("NOSIGNAL".parse::<Signal>(), errval);
-    assert_eq!("kill".parse::<Signal>(), errval);
-    assert_eq!("9".parse::<Signal>(), errval);
+    assert_eq!("SIG".parse::<Signal>(), errval);
+    assert_eq!("".parse::<Signal>(), errval);
+    assert_eq!("0".parse::<Signal>(), errval);
+    assert_eq!("-9".parse::<Signal>(), errval);
 }
 
 #[test]
@@ -273,6 +390,63 @@ fn test_thread_signal_swap() {
     .unwrap();
 }
 
+#[test]
+fn test_raise_pending() {
+    use nix::sys::wait::{waitpid, WaitStatus};
+
+    let _m = crate::FORK_MTX.lock();
+
+    // Safe: The child only calls sigprocmask, raise, sigpending, and _exit,
+    // which are async-signal-safe.
+    match unsafe { fork() }.expect("Error: Fork Failed") {
+        ForkResult::Child => {
+            let mask = SigSet::from(SIGUSR1);
+            let ok = mask.thread_block().is_ok()
+                && raise(SIGUSR1).is_ok()
+                && matches!(SigSet::pending(), Ok(set) if set.contains(SIGUSR1));
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) }
+        }
+        ForkResult::Parent { child } => {
+            assert_eq!(waitpid(child, None), Ok(WaitStatus::Exited(child, 0)));
+        }
+    }
+}
+
+#[test]
+#[cfg(not(target_os = "redox"))]
+fn test_thread_pending() {
+    let _m = crate::SIGNAL_MTX.lock();
+    thread::spawn(|| {
+        SigSet::from(SIGUSR2).thread_block().unwrap();
+        assert!(!SigSet::pending().unwrap().contains(SIGUSR2));
+
+        raise(SIGUSR2).unwrap();
+        assert!(SigSet::pending().unwrap().contains(SIGUSR2));
+
+        // Accept the signal, so that it is no longer pending.
+        assert_eq!(SigSet::from(SIGUSR2).wait(), Ok(SIGUSR2));
+        assert!(!SigSet::pending().unwrap().contains(SIGUSR2));
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_thread_signal_swap_restore() {
+    thread::spawn(|| {
+        let mask = SigSet::from(SIGUSR1);
+        let oldmask = mask.thread_swap_mask(SigmaskHow::SIG_BLOCK).unwrap();
+        assert!(!oldmask.contains(SIGUSR1));
+        assert!(SigSet::thread_get_mask().unwrap().contains(SIGUSR1));
+
+        // Restoring the returned mask unblocks the signal again.
+        oldmask.thread_set_mask().unwrap();
+        assert!(!SigSet::thread_get_mask().unwrap().contains(SIGUSR1));
+    })
+    .join()
+    .unwrap();
+}
+
 #[test]
 fn test_from_and_into_iterator() {
     let sigset = SigSet::from_iter(vec![Signal::SIGUSR1, Signal::SIGUSR2]);
@@ -343,6 +517,34 @@ fn test_sigwait() {
     .unwrap();
 }
 
+#[test]
+#[cfg(any(
+    linux_android,
+    freebsdlike,
+    target_os = "netbsd",
+    target_os = "haiku",
+    target_os = "hurd",
+    target_os = "aix",
+    target_os = "fuchsia"
+))]
+fn test_sigwait_timeout() {
+    use nix::sys::time::{TimeSpec, TimeValLike};
+
+    thread::spawn(|| {
+        let mut mask = SigSet::empty();
+        mask.add(SIGUSR1);
+        mask.thread_block().unwrap();
+
+        let timeout = TimeSpec::milliseconds(50);
+        assert_eq!(mask.wait_timeout(timeout).unwrap(), None);
+
+        raise(SIGUSR1).unwrap();
+        assert_eq!(mask.wait_timeout(timeout).unwrap(), Some(SIGUSR1));
+    })
+    .join()
+    .unwrap();
+}
+
 #[cfg(any(
     bsd,
     linux_android,
@@ -394,6 +596,68 @@ fn test_sigsuspend() {
     .unwrap();
 }
 
+#[cfg(linux_android)]
+#[test]
+fn test_sigsuspend_from_other_thread() {
+    use nix::sys::pthread::{pthread_kill, pthread_self};
+    use std::sync::mpsc;
+
+    // This test changes the signal handler
+    let _m = crate::SIGNAL_MTX.lock();
+    static SIGNAL_RECEIVED: AtomicBool = AtomicBool::new(false);
+    extern "C" fn test_sigsuspend_handler(_: libc::c_int) {
+        SIGNAL_RECEIVED.store(true, Ordering::SeqCst);
+    }
+    thread::spawn(|| {
+        const SIGNAL: Signal = Signal::SIGUSR2;
+
+        // Block the signal, so that it can only be delivered while suspended.
+        let mut signal_set = SigSet::empty();
+        signal_set.add(SIGNAL);
+        signal_set.thread_block().unwrap();
+
+        let act = SigAction::new(
+            SigHandler::Handler(test_sigsuspend_handler),
+            SaFlags::empty(),
+            SigSet::empty(),
+        );
+        let old_act = unsafe { sigaction(SIGNAL, &act) }
+            .expect("expect to be able to set new action and get old action");
+
+        let mut not_wait_set = SigSet::all();
+        not_wait_set.remove(SIGNAL);
+
+        let (tx, rx) = mpsc::channel();
+        let me = pthread_self();
+        let sender = thread::spawn(move || {
+            // Wait for the suspending thread to go to sleep, which it only
+            // does in `suspend`, so that the signal isn't just left pending.
+            let tid: Pid = rx.recv().unwrap();
+            let stat = format!("/proc/self/task/{tid}/stat");
+            while !std::fs::read_to_string(&stat)
+                .unwrap()
+                .rsplit(')')
+                .next()
+                .unwrap()
+                .starts_with(" S")
+            {
+                thread::yield_now();
+            }
+            pthread_kill(me, SIGNAL).unwrap();
+        });
+
+        tx.send(gettid()).unwrap();
+        not_wait_set.suspend().unwrap();
+        assert!(SIGNAL_RECEIVED.load(Ordering::SeqCst));
+        sender.join().unwrap();
+
+        unsafe { sigaction(SIGNAL, &old_act) }
+            .expect("expect to be able to restore old action ");
+    })
+    .join()
+    .unwrap();
+}
+
 #[test]
 fn test_from_sigset_t_unchecked() {
     let src_set = SigSet::empty();
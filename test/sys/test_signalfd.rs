@@ -88,3 +88,29 @@ fn test_signalfd_setmask() {
     let signo = Signal::try_from(res.ssi_signo as i32).unwrap();
     assert_eq!(signo, signal::SIGUSR1);
 }
+
+/// Extend the signal mask of an already existing signalfd.
+#[test]
+fn test_signalfd_setmask_add() {
+    use nix::sys::signal::{self, raise, SigSet, Signal};
+    use nix::sys::signalfd::SignalFd;
+
+    // Grab the mutex for altering signals so we don't interfere with other tests.
+    let _m = crate::SIGNAL_MTX.lock();
+
+    let mask = signal::SIGUSR1 | signal::SIGUSR2;
+    mask.thread_block().unwrap();
+
+    let fd = SignalFd::new(&SigSet::from(signal::SIGUSR1)).unwrap();
+    fd.set_mask(&mask).unwrap();
+
+    raise(signal::SIGUSR1).expect("Error: raise(SIGUSR1) failed");
+    raise(signal::SIGUSR2).expect("Error: raise(SIGUSR2) failed");
+
+    let mut received = SigSet::empty();
+    for _ in 0..2 {
+        let res = fd.read_signal().unwrap().unwrap();
+        received.add(Signal::try_from(res.ssi_signo as i32).unwrap());
+    }
+    assert_eq!(received, mask);
+}
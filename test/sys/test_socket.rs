@@ -72,6 +72,62 @@ pub fn test_timestamping() {
     assert!(std::time::Duration::from(diff).as_secs() < 60);
 }
 
+#[cfg(target_os = "linux")]
+#[cfg_attr(qemu, ignore)]
+#[test]
+pub fn test_recv_hw_timestamp() {
+    use nix::sys::socket::{
+        bind, getsockname, recv_hw_timestamp, sendto, setsockopt, socket,
+        sockopt::Timestamping, MsgFlags, SockFlag, SockType, SockaddrIn,
+        TimestampingFlag,
+    };
+
+    let ssock = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    let rsock = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    bind(
+        rsock.as_raw_fd(),
+        &SockaddrIn::from_str("127.0.0.1:0").unwrap(),
+    )
+    .unwrap();
+    let sock_addr: SockaddrIn = getsockname(rsock.as_raw_fd()).unwrap();
+
+    // Hardware timestamps need NIC support, so only ask for software ones
+    let flags = TimestampingFlag::SOF_TIMESTAMPING_SOFTWARE
+        | TimestampingFlag::SOF_TIMESTAMPING_RX_SOFTWARE;
+    setsockopt(&rsock, Timestamping, &flags).unwrap();
+
+    // The kernel turns on receive timestamps asynchronously, so the first
+    // packets may arrive without one.
+    let mut buf = [0u8; 16];
+    let ts = (0..100)
+        .find_map(|_| {
+            sendto(ssock.as_raw_fd(), b"hello", &sock_addr, MsgFlags::empty())
+                .unwrap();
+            let (len, ts) = recv_hw_timestamp(&rsock, &mut buf).unwrap();
+            assert_eq!(&buf[..len], b"hello");
+            ts.or_else(|| {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                None
+            })
+        })
+        .expect("SCM_TIMESTAMPING is present");
+    assert!(!ts.is_empty());
+    assert!(ts.software().is_some());
+    assert_eq!(ts.hardware_raw(), None);
+}
+
 #[cfg(target_os = "freebsd")]
 #[test]
 pub fn test_timestamping_realtime() {
@@ -307,6 +363,30 @@ pub fn test_getsockname() {
     );
 }
 
+#[test]
+pub fn test_getsockname_downcast() {
+    use nix::sys::socket::{bind, socket, SockFlag, SockType};
+    use nix::sys::socket::{SockaddrIn, SockaddrStorage};
+
+    let sock = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .expect("socket failed");
+    let sockaddr = SockaddrIn::from_str("127.0.0.1:0").unwrap();
+    bind(sock.as_raw_fd(), &sockaddr).expect("bind failed");
+
+    let storage: SockaddrStorage =
+        getsockname(sock.as_raw_fd()).expect("getsockname failed");
+    let sin = storage.as_sockaddr_in().unwrap();
+    assert_eq!(sin.ip(), std::net::Ipv4Addr::LOCALHOST);
+    assert_ne!(sin.port(), 0);
+    assert!(storage.as_sockaddr_in6().is_none());
+    assert!(storage.as_unix_addr().is_none());
+}
+
 #[test]
 pub fn test_socketpair() {
     use nix::sys::socket::{socketpair, AddressFamily, SockFlag, SockType};
@@ -366,6 +446,48 @@ pub fn test_recvmsg_sockaddr_un() {
     assert_eq!(sockaddr, received.address.unwrap());
 }
 
+#[test]
+pub fn test_recvmsg_source_in() {
+    use nix::sys::socket::{
+        bind, getsockname, recvmsg, sendto, socket, AddressFamily, MsgFlags,
+        SockFlag, SockType, SockaddrIn, SockaddrStorage,
+    };
+
+    let rsock = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    bind(rsock.as_raw_fd(), &SockaddrIn::new(127, 0, 0, 1, 0)).unwrap();
+    let raddr: SockaddrIn = getsockname(rsock.as_raw_fd()).unwrap();
+
+    let ssock = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    bind(ssock.as_raw_fd(), &SockaddrIn::new(127, 0, 0, 1, 0)).unwrap();
+    let saddr: SockaddrIn = getsockname(ssock.as_raw_fd()).unwrap();
+    sendto(ssock.as_raw_fd(), b"hello", &raddr, MsgFlags::empty()).unwrap();
+
+    let mut buf = [0u8; 5];
+    let mut iov = [std::io::IoSliceMut::new(&mut buf)];
+    let msg = recvmsg::<SockaddrStorage>(
+        rsock.as_raw_fd(),
+        &mut iov,
+        None,
+        MsgFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(Some(&saddr), msg.source_in());
+    assert_eq!(None, msg.source_in6());
+    assert_eq!(None, msg.source_unix());
+}
+
 #[test]
 pub fn test_std_conversions() {
     use nix::sys::socket::*;
@@ -556,7 +678,7 @@ mod recvfrom {
         }
     }
 
-    #[cfg(any(linux_android, target_os = "freebsd", target_os = "netbsd"))]
+    #[cfg(any(linux_android, target_os = "freebsd", netbsdlike))]
     #[test]
     pub fn udp_sendmmsg() {
         use std::io::IoSlice;
@@ -618,7 +740,12 @@ mod recvfrom {
         assert_eq!(AddressFamily::Inet, from.unwrap().family().unwrap());
     }
 
-    #[cfg(any(linux_android, target_os = "freebsd", target_os = "netbsd"))]
+    #[cfg(any(
+        linux_android,
+        target_os = "freebsd",
+        netbsdlike,
+        apple_targets
+    ))]
     #[test]
     pub fn udp_recvmmsg() {
         use nix::sys::socket::{recvmmsg, MsgFlags};
@@ -694,7 +821,12 @@ mod recvfrom {
         send_thread.join().unwrap();
     }
 
-    #[cfg(any(linux_android, target_os = "freebsd", target_os = "netbsd"))]
+    #[cfg(any(
+        linux_android,
+        target_os = "freebsd",
+        netbsdlike,
+        apple_targets
+    ))]
     #[test]
     pub fn udp_recvmmsg_dontwait_short_read() {
         use nix::sys::socket::{recvmmsg, MsgFlags};
@@ -821,6 +953,73 @@ mod recvfrom {
     }
 }
 
+#[cfg(linux_android)]
+#[test]
+pub fn test_recv_trunc() {
+    use nix::sys::socket::{
+        bind, recv_trunc, sendto, socket, MsgFlags, SockFlag, SockType,
+        SockaddrIn,
+    };
+
+    let rsock = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    bind(rsock.as_raw_fd(), &SockaddrIn::new(127, 0, 0, 1, 0)).unwrap();
+    let addr: SockaddrIn = getsockname(rsock.as_raw_fd()).unwrap();
+    let ssock = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+
+    let msg = [0xa5u8; 2000];
+    sendto(ssock.as_raw_fd(), &msg, &addr, MsgFlags::empty()).unwrap();
+
+    let mut buf = [0u8; 100];
+    let len = recv_trunc(&rsock, &mut buf, MsgFlags::empty()).unwrap();
+    assert_eq!(len, 2000);
+    assert_eq!(buf, [0xa5u8; 100]);
+}
+
+#[test]
+pub fn test_sockatmark() {
+    use nix::sys::socket::{recv, send, sockatmark, MsgFlags};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+    use std::time::Duration;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let sender = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (receiver, _) = listener.accept().unwrap();
+
+    send(sender.as_raw_fd(), b"ab", MsgFlags::empty()).unwrap();
+    send(sender.as_raw_fd(), b"!", MsgFlags::MSG_OOB).unwrap();
+
+    // Consume the normal data preceding the urgent byte
+    let mut buf = [0u8; 2];
+    let mut read = 0;
+    while read < buf.len() {
+        read += recv(receiver.as_raw_fd(), &mut buf[read..], MsgFlags::empty())
+            .unwrap();
+    }
+    assert_eq!(&buf, b"ab");
+
+    // The urgent pointer may arrive slightly after the normal data
+    for _ in 0..100 {
+        if sockatmark(&receiver).unwrap() {
+            return;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    panic!("socket never reached the out-of-band mark");
+}
+
 // Test error handling of our recvmsg wrapper
 #[test]
 pub fn test_recvmsg_ebadf() {
@@ -917,6 +1116,76 @@ pub fn test_scm_rights() {
     close(received_r).unwrap();
 }
 
+#[test]
+pub fn test_recv_fds_cloexec() {
+    use nix::fcntl::{fcntl, FcntlArg, FdFlag};
+    use nix::sys::socket::{
+        recv_fds, sendmsg, socketpair, AddressFamily, ControlMessage, MsgFlags,
+        SockFlag, SockType,
+    };
+    use nix::unistd::{pipe, read, write};
+    use std::io::IoSlice;
+
+    let (fd1, fd2) = socketpair(
+        AddressFamily::Unix,
+        SockType::Stream,
+        None,
+        SockFlag::empty(),
+    )
+    .unwrap();
+    let (r, w) = pipe().unwrap();
+
+    let iov = [IoSlice::new(b"hello")];
+    let fds = [r.as_raw_fd()];
+    let cmsg = ControlMessage::ScmRights(&fds);
+    sendmsg::<()>(fd1.as_raw_fd(), &iov, &[cmsg], MsgFlags::empty(), None)
+        .unwrap();
+
+    let mut buf = [0u8; 5];
+    let (bytes, received) = recv_fds(&fd2, &mut buf, 1).unwrap();
+    assert_eq!(bytes, 5);
+    assert_eq!(&buf, b"hello");
+    assert_eq!(received.len(), 1);
+
+    let flags = fcntl(&received[0], FcntlArg::F_GETFD).unwrap();
+    assert!(FdFlag::from_bits_truncate(flags).contains(FdFlag::FD_CLOEXEC));
+
+    write(&w, b"world").unwrap();
+    read(&received[0], &mut buf).unwrap();
+    assert_eq!(&buf, b"world");
+}
+
+#[test]
+pub fn test_send_fds() {
+    use nix::sys::socket::{
+        recv_fds, send_fds, socketpair, AddressFamily, SockFlag, SockType,
+    };
+    use nix::unistd::{pipe, read, write};
+
+    let (fd1, fd2) = socketpair(
+        AddressFamily::Unix,
+        SockType::Stream,
+        None,
+        SockFlag::empty(),
+    )
+    .unwrap();
+    let (r, w) = pipe().unwrap();
+
+    assert_eq!(send_fds(&fd1, &[r.as_raw_fd()], b"pipe").unwrap(), 4);
+    // The sender's copy can be closed; the receiver gets its own.
+    drop(r);
+
+    let mut buf = [0u8; 4];
+    let (bytes, received) = recv_fds(&fd2, &mut buf, 1).unwrap();
+    assert_eq!(bytes, 4);
+    assert_eq!(&buf, b"pipe");
+    assert_eq!(received.len(), 1);
+
+    write(&w, b"data").unwrap();
+    read(&received[0], &mut buf).unwrap();
+    assert_eq!(&buf, b"data");
+}
+
 // Disable the test on emulated platforms due to not enabled support of AF_ALG in QEMU from rust cross
 #[cfg(linux_android)]
 #[cfg_attr(qemu, ignore)]
@@ -2320,6 +2589,175 @@ pub fn test_vsock() {
     assert_eq!(addr3.as_ref().svm_port, addr1.port());
 }
 
+#[cfg(target_os = "linux")]
+#[test]
+pub fn test_can_addr() {
+    use nix::sys::socket::{
+        AddressFamily, CanAddr, SockaddrLike, SockaddrStorage,
+    };
+
+    let addr = CanAddr::new(3);
+    assert_eq!(addr.ifindex(), 3);
+    assert_eq!(addr.family(), Some(AddressFamily::Can));
+    assert_ne!(addr, CanAddr::new(4));
+    assert_eq!(calculate_hash(&addr), calculate_hash(&CanAddr::new(3)));
+
+    let ss = unsafe {
+        SockaddrStorage::from_raw(addr.as_ptr(), Some(addr.len())).unwrap()
+    };
+    assert_eq!(ss.as_can_addr(), Some(&addr));
+
+    // CAN_RAW and CAN_BCM sockets only report the address up to can_ifindex.
+    let short = unsafe { CanAddr::from_raw(addr.as_ptr(), Some(8)).unwrap() };
+    assert_eq!(short, addr);
+    assert!(unsafe { CanAddr::from_raw(addr.as_ptr(), Some(4)) }.is_none());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+pub fn test_can_raw_bind() {
+    use nix::net::if_::if_nametoindex;
+    use nix::sys::socket::{
+        bind, getsockname, socket, AddressFamily, CanAddr, SockFlag,
+        SockProtocol, SockType,
+    };
+
+    let ifindex = match if_nametoindex("vcan0") {
+        Ok(ifindex) => ifindex,
+        Err(_) => skip!("No vcan0 interface. Skipping test."),
+    };
+    let fd = socket(
+        AddressFamily::Can,
+        SockType::Raw,
+        SockFlag::empty(),
+        SockProtocol::CanRaw,
+    )
+    .unwrap();
+    let addr = CanAddr::new(ifindex as i32);
+    bind(fd.as_raw_fd(), &addr).unwrap();
+    assert_eq!(getsockname::<CanAddr>(fd.as_raw_fd()), Ok(addr));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+pub fn test_can_bcm_frame() {
+    use nix::sys::socket::can::{
+        recv_bcm, send_bcm, BcmFlags, BcmOpcode, CanBcmFrame,
+    };
+    use nix::sys::socket::{socketpair, AddressFamily, SockFlag, SockType};
+    use nix::sys::time::{TimeVal, TimeValLike};
+
+    // The framing doesn't depend on the socket, so exercise it over a pair of
+    // Unix datagram sockets.
+    let (fd1, fd2) = socketpair(
+        AddressFamily::Unix,
+        SockType::Datagram,
+        None,
+        SockFlag::empty(),
+    )
+    .unwrap();
+    let msg = CanBcmFrame::new(BcmOpcode::TX_SETUP, 0x123)
+        .flags(BcmFlags::SETTIMER | BcmFlags::STARTTIMER)
+        .count(3)
+        .ival1(TimeVal::milliseconds(10))
+        .ival2(TimeVal::seconds(1))
+        .frame(0x123, &[1, 2, 3])
+        .frame(0x123, &[4, 5]);
+    send_bcm(&fd1, &msg).unwrap();
+
+    let received = recv_bcm(&fd2).unwrap();
+    assert_eq!(received, msg);
+    assert_eq!(received.opcode(), Ok(BcmOpcode::TX_SETUP));
+    assert_eq!(received.can_id(), 0x123);
+    assert_eq!(received.frames().len(), 2);
+    assert_eq!(received.frames()[1].can_dlc, 2);
+    assert_eq!(&received.frames()[1].data[..2], &[4, 5]);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+pub fn test_can_bcm_tx_setup() {
+    use nix::net::if_::if_nametoindex;
+    use nix::sys::socket::can::{send_bcm, BcmFlags, BcmOpcode, CanBcmFrame};
+    use nix::sys::socket::{
+        connect, socket, AddressFamily, CanAddr, SockFlag, SockProtocol,
+        SockType,
+    };
+    use nix::sys::time::{TimeVal, TimeValLike};
+
+    let ifindex = match if_nametoindex("vcan0") {
+        Ok(ifindex) => ifindex,
+        Err(_) => skip!("No vcan0 interface. Skipping test."),
+    };
+    let fd = socket(
+        AddressFamily::Can,
+        SockType::Datagram,
+        SockFlag::empty(),
+        SockProtocol::CanBcm,
+    )
+    .unwrap();
+    connect(fd.as_raw_fd(), &CanAddr::new(ifindex as i32)).unwrap();
+
+    let msg = CanBcmFrame::new(BcmOpcode::TX_SETUP, 0x123)
+        .flags(BcmFlags::SETTIMER | BcmFlags::STARTTIMER)
+        .ival2(TimeVal::milliseconds(100))
+        .frame(0x123, &[0xde, 0xad]);
+    let len = send_bcm(&fd, &msg).unwrap();
+    assert!(len > 0);
+    send_bcm(&fd, &CanBcmFrame::new(BcmOpcode::TX_DELETE, 0x123)).unwrap();
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[test]
+pub fn test_xdp_addr() {
+    use nix::sys::socket::{
+        AddressFamily, SockaddrLike, SockaddrStorage, XdpAddr, XdpFlags,
+    };
+
+    let addr = XdpAddr::new(1, 2, XdpFlags::XDP_COPY);
+    assert_eq!(addr.ifindex(), 1);
+    assert_eq!(addr.queue_id(), 2);
+    assert_eq!(addr.flags(), XdpFlags::XDP_COPY);
+    assert_eq!(addr.family(), Some(AddressFamily::Xdp));
+    assert_ne!(addr, XdpAddr::new(1, 3, XdpFlags::XDP_COPY));
+
+    let ss = unsafe {
+        SockaddrStorage::from_raw(addr.as_ptr(), Some(addr.len())).unwrap()
+    };
+    assert_eq!(ss.as_xdp_addr(), Some(&addr));
+    assert_eq!(ss.as_vsock_addr(), None);
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[test]
+pub fn test_xdp_socket() {
+    use nix::errno::Errno;
+    use nix::sys::socket::{
+        getsockopt_raw, socket, AddressFamily, SockFlag, SockType,
+    };
+
+    require_capability!("test_xdp_socket", CAP_NET_RAW);
+    let fd = match socket(
+        AddressFamily::Xdp,
+        SockType::Raw,
+        SockFlag::empty(),
+        None,
+    ) {
+        Ok(fd) => fd,
+        Err(Errno::EAFNOSUPPORT) => {
+            skip!("AF_XDP is not supported by this kernel. Skipping test.")
+        }
+        Err(e) => panic!("socket failed: {e}"),
+    };
+
+    let mut domain = [0u8; std::mem::size_of::<libc::c_int>()];
+    let len =
+        getsockopt_raw(&fd, libc::SOL_SOCKET, libc::SO_DOMAIN, &mut domain)
+            .unwrap();
+    assert_eq!(len, domain.len());
+    assert_eq!(libc::c_int::from_ne_bytes(domain), libc::AF_XDP);
+}
+
 #[cfg(apple_targets)]
 #[test]
 pub fn test_vsock() {
@@ -2580,6 +3018,54 @@ fn test_recvmsg_rxq_ovfl() {
     assert_eq!(drop_counter, 1);
 }
 
+#[cfg_attr(qemu, ignore)]
+#[cfg(any(linux_android, target_os = "fuchsia"))]
+#[test]
+fn test_recv_with_drops() {
+    use nix::sys::socket::*;
+
+    let in_socket = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    let out_socket = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    setsockopt(&in_socket, sockopt::RxqOvfl, &1).unwrap();
+
+    let localhost = SockaddrIn::from_str("127.0.0.1:0").unwrap();
+    bind(in_socket.as_raw_fd(), &localhost).unwrap();
+    bind(out_socket.as_raw_fd(), &localhost).unwrap();
+    let address: SockaddrIn = getsockname(in_socket.as_raw_fd()).unwrap();
+    let sender: SockaddrIn = getsockname(out_socket.as_raw_fd()).unwrap();
+
+    sendto(
+        out_socket.as_raw_fd(),
+        b"hello",
+        &address,
+        MsgFlags::empty(),
+    )
+    .unwrap();
+
+    let mut buf = [0u8; 16];
+    let (len, from, drops) = recv_with_drops::<_, SockaddrIn>(
+        &in_socket,
+        &mut buf,
+        MsgFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buf[..len], b"hello");
+    assert_eq!(from, Some(sender));
+    assert_eq!(drops, None);
+}
+
 #[cfg(any(linux_android, target_os = "freebsd"))]
 #[cfg(feature = "net")]
 // qemu doesn't seem to be emulating this correctly in these architectures
@@ -2870,6 +3356,77 @@ mod linux_errqueue {
         )
     }
 
+    // Send a UDP datagram with MSG_ZEROCOPY over loopback and read back the
+    // completion notification.
+    #[cfg(target_os = "linux")]
+    #[cfg_attr(qemu, ignore)]
+    #[test]
+    fn test_zerocopy_completion() {
+        use nix::errno::Errno;
+        use std::io::IoSliceMut;
+        use std::thread;
+        use std::time::Duration;
+
+        let localhost = SockaddrIn::from_str("127.0.0.1:0").unwrap();
+        let rsock = socket(
+            AddressFamily::Inet,
+            SockType::Datagram,
+            SockFlag::SOCK_CLOEXEC,
+            None,
+        )
+        .unwrap();
+        bind(rsock.as_raw_fd(), &localhost).unwrap();
+        let raddr: SockaddrIn = getsockname(rsock.as_raw_fd()).unwrap();
+
+        let ssock = socket(
+            AddressFamily::Inet,
+            SockType::Datagram,
+            SockFlag::SOCK_CLOEXEC,
+            None,
+        )
+        .unwrap();
+        match setsockopt(&ssock, sockopt::ZeroCopy, &true) {
+            Ok(()) => (),
+            Err(Errno::ENOPROTOOPT | Errno::EOPNOTSUPP) => {
+                skip!("SO_ZEROCOPY is not supported. Skipping test.")
+            }
+            Err(e) => panic!("setsockopt failed: {e}"),
+        }
+        assert!(getsockopt(&ssock, sockopt::ZeroCopy).unwrap());
+        sendto(
+            ssock.as_raw_fd(),
+            b"zerocopy",
+            &raddr,
+            MsgFlags::MSG_ZEROCOPY,
+        )
+        .unwrap();
+
+        let mut buf = [0u8; 8];
+        let mut iovec = [IoSliceMut::new(&mut buf)];
+        let mut cspace = cmsg_space!(libc::sock_extended_err, sockaddr_in);
+        let mut attempts = 0;
+        let msg = loop {
+            match recvmsg::<()>(
+                ssock.as_raw_fd(),
+                &mut iovec,
+                Some(&mut cspace),
+                MsgFlags::MSG_ERRQUEUE,
+            ) {
+                Err(Errno::EAGAIN) if attempts < 100 => {
+                    attempts += 1;
+                    thread::sleep(Duration::from_millis(10));
+                }
+                res => break res.unwrap(),
+            }
+        };
+        match msg.cmsgs().unwrap().next() {
+            Some(ControlMessageOwned::ZeroCopyCompletion(completion)) => {
+                assert_eq!(completion.range(), 0..=0);
+            }
+            cmsg => panic!("Unexpected control message {cmsg:?}"),
+        }
+    }
+
     fn test_recverr_impl<SA, OPT, TESTF>(
         sa: &str,
         af: AddressFamily,
@@ -3160,3 +3717,39 @@ fn can_open_routing_socket() {
         socket(AddressFamily::Route, SockType::Raw, SockFlag::empty(), None)
             .expect("Failed to open routing socket");
 }
+
+#[cfg(any(target_os = "linux", freebsdlike))]
+#[test]
+pub fn test_accept_addr() {
+    use nix::sys::socket::{
+        accept_addr, bind, listen, socket, Backlog, SockFlag, SockType,
+        SockaddrIn, SockaddrLike, SockaddrStorage,
+    };
+    use std::net::TcpStream;
+
+    let listener = socket(
+        AddressFamily::Inet,
+        SockType::Stream,
+        SockFlag::SOCK_CLOEXEC,
+        None,
+    )
+    .unwrap();
+    bind(
+        listener.as_raw_fd(),
+        &SockaddrIn::from_str("127.0.0.1:0").unwrap(),
+    )
+    .unwrap();
+    listen(&listener, Backlog::new(1).unwrap()).unwrap();
+    let addr: SockaddrIn = getsockname(listener.as_raw_fd()).unwrap();
+
+    let client = TcpStream::connect(SocketAddrV4::from(addr)).unwrap();
+    let (_session, peer) =
+        accept_addr::<SockaddrStorage>(listener.as_raw_fd(), SockFlag::empty())
+            .unwrap();
+    let peer = peer.unwrap();
+    assert_eq!(peer.family(), Some(AddressFamily::Inet));
+    assert_eq!(
+        peer.as_sockaddr_in().unwrap().port(),
+        client.local_addr().unwrap().port()
+    );
+}
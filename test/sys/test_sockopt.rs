@@ -96,7 +96,7 @@ pub fn test_local_peer_token() {
     );
 }
 
-#[cfg(target_os = "linux")]
+#[cfg(linux_android)]
 #[test]
 fn is_so_mark_functional() {
     use nix::sys::socket::sockopt;
@@ -220,6 +220,41 @@ fn test_so_tcp_maxseg() {
     }
 }
 
+#[test]
+fn test_so_rcvtimeo() {
+    use nix::sys::socket::{bind, recv, MsgFlags, SockaddrIn};
+    use nix::sys::time::{TimeVal, TimeValLike};
+    use std::net::SocketAddrV4;
+    use std::str::FromStr;
+    use std::time::{Duration, Instant};
+
+    let fd = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    let std_sa = SocketAddrV4::from_str("127.0.0.1:0").unwrap();
+    bind(fd.as_raw_fd(), &SockaddrIn::from(std_sa)).unwrap();
+
+    assert_eq!(
+        getsockopt(&fd, sockopt::ReceiveTimeout).unwrap(),
+        TimeVal::zero()
+    );
+    let timeout = TimeVal::milliseconds(100);
+    setsockopt(&fd, sockopt::ReceiveTimeout, &timeout).unwrap();
+    assert_eq!(getsockopt(&fd, sockopt::ReceiveTimeout).unwrap(), timeout);
+
+    let start = Instant::now();
+    let mut buf = [0u8; 8];
+    assert_eq!(
+        recv(fd.as_raw_fd(), &mut buf, MsgFlags::empty()),
+        Err(nix::errno::Errno::EAGAIN)
+    );
+    assert!(start.elapsed() >= Duration::from_millis(90));
+}
+
 #[test]
 fn test_so_type() {
     let sockfd = socket(
@@ -286,6 +321,19 @@ fn test_tcp_congestion() {
     )
     .unwrap_err();
 
+    // Names the kernel would truncate are rejected up front.
+    assert_eq!(
+        setsockopt(
+            &fd,
+            sockopt::TcpCongestion,
+            &OsString::from("cubic01234567890")
+        )
+        .unwrap_err(),
+        nix::errno::Errno::EINVAL
+    );
+
+    assert!(!val.is_empty());
+
     assert_eq!(getsockopt(&fd, sockopt::TcpCongestion).unwrap(), val);
 }
 
@@ -337,6 +385,24 @@ fn test_bindtodevice() {
     assert_eq!(getsockopt(&fd, sockopt::BindToDevice).unwrap(), val);
 }
 
+#[test]
+#[cfg(linux_android)]
+fn test_bindtodevice_name_too_long() {
+    let fd = socket(
+        AddressFamily::Inet,
+        SockType::Stream,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+
+    let name = std::ffi::OsString::from("x".repeat(libc::IFNAMSIZ));
+    assert_eq!(
+        setsockopt(&fd, sockopt::BindToDevice, &name),
+        Err(nix::errno::Errno::EINVAL)
+    );
+}
+
 #[test]
 fn test_so_tcp_keepalive() {
     let fd = socket(
@@ -365,6 +431,54 @@ fn test_so_tcp_keepalive() {
     }
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_tcp_info() {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut client =
+        TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (mut server, _) = listener.accept().unwrap();
+
+    let mut buf = [0u8; 4];
+    client.write_all(b"ping").unwrap();
+    server.read_exact(&mut buf).unwrap();
+    server.write_all(b"pong").unwrap();
+    client.read_exact(&mut buf).unwrap();
+
+    let info = getsockopt(&client, sockopt::TcpInfo).unwrap();
+    // TCP_ESTABLISHED
+    assert_eq!(info.state(), 1);
+    assert!(info.rtt() > 0);
+    assert!(info.snd_cwnd() > 0);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_so_cookie() {
+    let s1 = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    let s2 = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    let c1 = getsockopt(&s1, sockopt::Cookie).unwrap();
+    let c2 = getsockopt(&s2, sockopt::Cookie).unwrap();
+    assert_ne!(c1, 0);
+    assert_ne!(c1, c2);
+    assert_eq!(c1, getsockopt(&s1, sockopt::Cookie).unwrap());
+}
+
 #[test]
 #[cfg(linux_android)]
 #[cfg_attr(qemu, ignore)]
@@ -748,6 +862,10 @@ fn can_get_peercred_on_unix_socket() {
     let b_cred = getsockopt(&b, sockopt::PeerCredentials).unwrap();
     assert_eq!(a_cred, b_cred);
     assert_ne!(a_cred.pid(), 0);
+    // Both ends were created by this process.
+    assert_eq!(a_cred.pid(), nix::unistd::getpid().as_raw());
+    assert_eq!(a_cred.uid(), nix::unistd::getuid().as_raw());
+    assert_eq!(a_cred.gid(), nix::unistd::getgid().as_raw());
 }
 
 #[test]
@@ -945,6 +1063,32 @@ fn test_reuseport_lb() {
     assert!(getsockopt(&fd, sockopt::ReusePortLb).unwrap());
 }
 
+#[test]
+#[cfg(target_os = "freebsd")]
+fn test_reuseport_lb_bind() {
+    use nix::sys::socket::{bind, getsockname, SockaddrIn};
+    use std::net::SocketAddrV4;
+    use std::str::FromStr;
+
+    let std_sa = SocketAddrV4::from_str("127.0.0.1:0").unwrap();
+    let mut sock_addr = SockaddrIn::from(std_sa);
+    let mut socks = Vec::new();
+    for _ in 0..2 {
+        let fd = socket(
+            AddressFamily::Inet,
+            SockType::Datagram,
+            SockFlag::empty(),
+            None,
+        )
+        .unwrap();
+        setsockopt(&fd, sockopt::ReusePortLb, &true).unwrap();
+        // The second socket binds to the port picked for the first one.
+        bind(fd.as_raw_fd(), &sock_addr).unwrap();
+        sock_addr = getsockname(fd.as_raw_fd()).unwrap();
+        socks.push(fd);
+    }
+}
+
 #[test]
 #[cfg(any(linux_android, target_os = "freebsd"))]
 fn test_ipv4_recv_ttl_opts() {
@@ -1168,3 +1312,32 @@ fn test_exclbind() {
         Err(Errno::EADDRINUSE)
     );
 }
+
+#[test]
+fn test_sockopt_raw() {
+    use nix::sys::socket::{getsockopt_raw, setsockopt_raw};
+
+    let fd = socket(
+        AddressFamily::Inet,
+        SockType::Stream,
+        SockFlag::empty(),
+        None,
+    )
+    .unwrap();
+    let on: libc::c_int = 1;
+    setsockopt_raw(
+        &fd,
+        libc::SOL_SOCKET,
+        libc::SO_REUSEADDR,
+        &on.to_ne_bytes(),
+    )
+    .unwrap();
+
+    let mut buf = [0u8; std::mem::size_of::<libc::c_int>()];
+    let len =
+        getsockopt_raw(&fd, libc::SOL_SOCKET, libc::SO_REUSEADDR, &mut buf)
+            .unwrap();
+    assert_eq!(len, buf.len());
+    assert_ne!(libc::c_int::from_ne_bytes(buf), 0);
+    assert!(getsockopt(&fd, sockopt::ReuseAddr).unwrap());
+}
@@ -67,3 +67,58 @@ pub fn test_timerfd_unset() {
 
     assert!(timer.get().unwrap().is_none());
 }
+
+#[test]
+pub fn test_timerfd_get() {
+    let timer =
+        TimerFd::new(ClockId::CLOCK_MONOTONIC, TimerFlags::empty()).unwrap();
+
+    timer
+        .set(
+            Expiration::Interval(TimeSpec::seconds(1)),
+            TimerSetTimeFlags::empty(),
+        )
+        .unwrap();
+
+    // The remaining time has already started counting down, so it is reported
+    // separately from the interval.
+    match timer.get().unwrap() {
+        Some(Expiration::IntervalDelayed(remaining, interval)) => {
+            assert!(remaining > TimeSpec::seconds(0));
+            assert!(remaining <= TimeSpec::seconds(1));
+            assert_eq!(interval, TimeSpec::seconds(1));
+        }
+        expiration => panic!("Unexpected expiration {expiration:?}"),
+    }
+
+    timer
+        .set(
+            Expiration::OneShot(TimeSpec::seconds(1)),
+            TimerSetTimeFlags::empty(),
+        )
+        .unwrap();
+    match timer.get().unwrap() {
+        Some(Expiration::OneShot(remaining)) => {
+            assert!(remaining > TimeSpec::seconds(0));
+            assert!(remaining <= TimeSpec::seconds(1));
+        }
+        expiration => panic!("Unexpected expiration {expiration:?}"),
+    }
+}
+
+#[test]
+pub fn test_timerfd_wait_count() {
+    let timer =
+        TimerFd::new(ClockId::CLOCK_MONOTONIC, TimerFlags::empty()).unwrap();
+
+    timer
+        .set(
+            Expiration::Interval(TimeSpec::milliseconds(20)),
+            TimerSetTimeFlags::empty(),
+        )
+        .unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(100));
+
+    // All expirations during the sleep are reported at once.
+    assert!(timer.wait().unwrap() >= 4);
+}
@@ -219,6 +219,25 @@ fn test_preadv() {
     assert_eq!(all, expected);
 }
 
+#[test]
+#[cfg(all(target_os = "linux", any(target_env = "gnu", target_env = "musl")))]
+fn test_pwritev2_append() {
+    let file = tempfile().unwrap();
+    assert_eq!(pwrite(&file, b"hello", 0), Ok(5));
+
+    // RWF_APPEND ignores the offset and writes at EOF.
+    let iovecs = [IoSlice::new(b" "), IoSlice::new(b"world")];
+    assert_eq!(pwritev2(&file, &iovecs, Some(0), RWFlag::RWF_APPEND), Ok(6));
+
+    let mut buf = [0u8; 11];
+    let mut iovecs = [IoSliceMut::new(&mut buf)];
+    assert_eq!(
+        preadv2(&file, &mut iovecs, Some(0), RWFlag::empty()),
+        Ok(11)
+    );
+    assert_eq!(&buf, b"hello world");
+}
+
 #[test]
 #[cfg(all(target_os = "linux", not(target_env = "uclibc")))]
 // uclibc doesn't implement process_vm_readv
@@ -273,3 +292,48 @@ fn test_process_vm_readv() {
         }
     }
 }
+
+#[test]
+#[cfg(all(target_os = "linux", not(target_env = "uclibc")))]
+// uclibc doesn't implement process_vm_writev
+// qemu-user doesn't implement process_vm_readv/writev on most arches
+#[cfg_attr(qemu, ignore)]
+fn test_process_vm_writev() {
+    use crate::*;
+    use nix::sys::wait::*;
+    use nix::unistd::ForkResult::*;
+
+    require_capability!("test_process_vm_writev", CAP_SYS_PTRACE);
+    let _m = crate::FORK_MTX.lock();
+
+    let vector = [0u8; 5];
+
+    let (r, w) = pipe().unwrap();
+    match unsafe { fork() }.expect("Error: Fork Failed") {
+        Parent { child } => {
+            drop(r);
+
+            let ptr = vector.as_ptr() as usize;
+            let remote_iov = RemoteIoVec { base: ptr, len: 5 };
+            let ret = process_vm_writev(
+                child,
+                &[IoSlice::new(&[1u8, 2, 3, 4, 5])],
+                &[remote_iov],
+            );
+            // tell the child to check its memory
+            write(&w, b"\0").unwrap();
+            drop(w);
+
+            let status = waitpid(child, None).unwrap();
+            assert_eq!(Ok(5), ret);
+            assert_eq!(WaitStatus::Exited(child, 0), status);
+        }
+        Child => {
+            drop(w);
+            let _ = read(&r, &mut [0u8]);
+            let ok =
+                unsafe { std::ptr::read_volatile(&vector) } == [1, 2, 3, 4, 5];
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) };
+        }
+    }
+}
@@ -26,6 +26,33 @@ fn test_wait_signal() {
     }
 }
 
+#[test]
+#[cfg(not(any(target_os = "redox", target_os = "haiku")))]
+fn test_try_wait() {
+    let _m = crate::FORK_MTX.lock();
+
+    // Safe: The child only calls `pause` and/or `_exit`, which are async-signal-safe.
+    match unsafe { fork() }.expect("Error: Fork Failed") {
+        Child => {
+            pause();
+            unsafe { _exit(123) }
+        }
+        Parent { child } => {
+            assert_eq!(try_wait(child, WaitPidFlag::empty()), Ok(None));
+            kill(child, Some(SIGKILL)).expect("Error: Kill Failed");
+            let status = loop {
+                if let Some(status) =
+                    try_wait(child, WaitPidFlag::empty()).unwrap()
+                {
+                    break status;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            };
+            assert_eq!(status, WaitStatus::Signaled(child, SIGKILL, false));
+        }
+    }
+}
+
 #[test]
 #[cfg(any(
     target_os = "android",
@@ -150,6 +177,36 @@ fn test_waitid_pid() {
     }
 }
 
+#[test]
+#[cfg(any(
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "haiku",
+    all(target_os = "linux", not(target_env = "uclibc")),
+))]
+fn test_waitid_nowait() {
+    let _m = crate::FORK_MTX.lock();
+
+    // Safe: Child only calls `_exit`, which is async-signal-safe.
+    match unsafe { fork() }.expect("Error: Fork Failed") {
+        Child => unsafe {
+            _exit(12);
+        },
+        Parent { child } => {
+            // WNOWAIT leaves the child waitable, so it can be waited for twice.
+            assert_eq!(
+                waitid(
+                    Id::Pid(child),
+                    WaitPidFlag::WEXITED | WaitPidFlag::WNOWAIT
+                ),
+                Ok(WaitStatus::Exited(child, 12)),
+            );
+            assert_eq!(waitpid(child, None), Ok(WaitStatus::Exited(child, 12)));
+            assert_eq!(waitpid(child, None), Err(Errno::ECHILD));
+        }
+    }
+}
+
 #[cfg(linux_android)]
 // FIXME: qemu-user doesn't implement ptrace on most arches
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
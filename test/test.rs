@@ -76,6 +76,8 @@ pub static CWD_LOCK: RwLock<()> = RwLock::new(());
 pub static GROUPS_MTX: Mutex<()> = Mutex::new(());
 /// Any tests that loads or unloads kernel modules must grab this mutex
 pub static KMOD_MTX: Mutex<()> = Mutex::new(());
+/// Any test that changes the process's RLIMIT_NOFILE must grab this mutex.
+pub static NOFILE_MTX: Mutex<()> = Mutex::new(());
 /// Any test that calls ptsname(3) must grab this mutex.
 pub static PTSNAME_MTX: Mutex<()> = Mutex::new(());
 /// Any test that alters signal handling must grab this mutex.
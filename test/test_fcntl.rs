@@ -112,6 +112,26 @@ fn test_openat2_forbidden() {
     assert_eq!(res.unwrap_err(), Errno::EXDEV);
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+// QEMU does not handle openat well enough to satisfy this test
+// https://gitlab.com/qemu-project/qemu/-/issues/829
+#[cfg_attr(qemu, ignore)]
+fn test_openat2_no_symlinks() {
+    let tempdir = tempfile::tempdir().unwrap();
+    File::create(tempdir.path().join("target")).unwrap();
+    std::os::unix::fs::symlink("target", tempdir.path().join("link")).unwrap();
+
+    let dirfd = open(tempdir.path(), OFlag::empty(), Mode::empty()).unwrap();
+    let how = OpenHow::new()
+        .flags(OFlag::O_RDONLY)
+        .resolve(ResolveFlag::RESOLVE_NO_SYMLINKS);
+
+    openat2(&dirfd, "target", how).unwrap();
+    let res = openat2(&dirfd, "link", how);
+    assert_eq!(res.unwrap_err(), Errno::ELOOP);
+}
+
 #[test]
 #[cfg(not(target_os = "redox"))]
 fn test_renameat() {
@@ -330,6 +350,40 @@ mod linux_android {
         assert_eq!(7, offset);
     }
 
+    #[test]
+    fn test_splice_pipe_to_file() {
+        let mut tmp = tempfile().unwrap();
+        let (rd, wr) = pipe().unwrap();
+        write(wr, b"abcdef").unwrap();
+
+        let mut offset: loff_t = 2;
+        let res = splice(
+            rd,
+            None,
+            tmp.try_clone().unwrap(),
+            Some(&mut offset),
+            6,
+            SpliceFFlags::empty(),
+        )
+        .unwrap();
+        assert_eq!(6, res);
+        assert_eq!(8, offset);
+
+        let mut buf = Vec::new();
+        tmp.read_to_end(&mut buf).unwrap();
+        assert_eq!(b"\0\0abcdef", &buf[..]);
+    }
+
+    #[test]
+    fn test_splice_no_pipe() {
+        use nix::errno::Errno;
+
+        let tmp1 = tempfile().unwrap();
+        let tmp2 = tempfile().unwrap();
+        let res = splice(tmp1, None, tmp2, None, 1, SpliceFFlags::empty());
+        assert_eq!(res, Err(Errno::EINVAL));
+    }
+
     #[test]
     fn test_tee() {
         let (rd1, wr1) = pipe().unwrap();
@@ -370,6 +424,77 @@ mod linux_android {
         assert_eq!(b"abcdef", &buf[0..6]);
     }
 
+    #[test]
+    fn test_pipe_size() {
+        let (rd, _wr) = pipe().unwrap();
+
+        let before = fcntl(&rd, FcntlArg::F_GETPIPE_SZ).unwrap();
+        let requested = before * 2 + 1;
+        let set = fcntl(&rd, FcntlArg::F_SETPIPE_SZ(requested)).unwrap();
+        // The kernel rounds the requested size up
+        assert!(set >= requested);
+        assert_eq!(fcntl(&rd, FcntlArg::F_GETPIPE_SZ).unwrap(), set);
+    }
+
+    #[test]
+    fn test_open_tmpfile() {
+        use nix::errno::Errno;
+        use nix::sys::stat::Mode;
+        use nix::unistd::linkat;
+        use std::os::unix::io::AsRawFd;
+
+        let tempdir = tempfile::tempdir().unwrap();
+        let fd = match open_tmpfile(
+            tempdir.path(),
+            OFlag::O_RDWR,
+            Mode::S_IRUSR | Mode::S_IWUSR,
+        ) {
+            Ok(fd) => fd,
+            Err(Errno::EOPNOTSUPP) => {
+                skip!("O_TMPFILE is not supported here. Skipping test.")
+            }
+            Err(e) => panic!("open_tmpfile failed: {e}"),
+        };
+        write(&fd, b"hello").unwrap();
+        // Still unnamed
+        assert_eq!(std::fs::read_dir(tempdir.path()).unwrap().count(), 0);
+
+        let proc_path = format!("/proc/self/fd/{}", fd.as_raw_fd());
+        let path = tempdir.path().join("linked");
+        linkat(
+            AT_FDCWD,
+            proc_path.as_str(),
+            AT_FDCWD,
+            path.to_str().unwrap(),
+            AtFlags::AT_SYMLINK_FOLLOW,
+        )
+        .unwrap();
+        drop(fd);
+        assert_eq!(std::fs::read(path).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn test_seals() {
+        use nix::errno::Errno;
+        use nix::sys::memfd::{memfd_create, MFdFlags};
+
+        let fd =
+            memfd_create("test_seals", MFdFlags::MFD_ALLOW_SEALING).unwrap();
+        assert_eq!(get_seals(&fd).unwrap(), SealFlag::empty());
+        write(&fd, b"abc").unwrap();
+
+        add_seals(&fd, SealFlag::F_SEAL_WRITE | SealFlag::F_SEAL_SEAL).unwrap();
+        assert_eq!(
+            get_seals(&fd).unwrap(),
+            SealFlag::F_SEAL_WRITE | SealFlag::F_SEAL_SEAL
+        );
+        assert_eq!(write(&fd, b"def").unwrap_err(), Errno::EPERM);
+        assert_eq!(
+            add_seals(&fd, SealFlag::F_SEAL_GROW).unwrap_err(),
+            Errno::EPERM
+        );
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn test_fallocate() {
@@ -382,6 +507,36 @@ mod linux_android {
         assert_eq!(100, read(&tmp, &mut buf).unwrap());
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_fallocate_punch_hole() {
+        use nix::errno::Errno;
+        use nix::unistd::{lseek, Whence};
+
+        const MIB: libc::off_t = 1 << 20;
+        const HOLE: libc::off_t = 64 << 10;
+        let mut tmp = NamedTempFile::new().unwrap();
+        fallocate(&tmp, FallocateFlags::empty(), 0, MIB).unwrap();
+        tmp.write_all(&[0xaa; MIB as usize]).unwrap();
+
+        match fallocate(
+            &tmp,
+            FallocateFlags::FALLOC_FL_PUNCH_HOLE
+                | FallocateFlags::FALLOC_FL_KEEP_SIZE,
+            HOLE,
+            HOLE,
+        ) {
+            Err(Errno::EOPNOTSUPP) => {
+                skip!("Punching holes is not supported. Skipping test.")
+            }
+            res => res.unwrap(),
+        }
+
+        assert_eq!(tmp.as_file().metadata().unwrap().len(), MIB as u64);
+        assert_eq!(lseek(&tmp, 0, Whence::SeekHole).unwrap(), HOLE);
+        assert_eq!(lseek(&tmp, HOLE, Whence::SeekData).unwrap(), 2 * HOLE);
+    }
+
     // The tests below are disabled for the listed targets
     // due to OFD locks not being available in the kernel/libc
     // versions used in the CI environment, probably because
@@ -425,6 +580,60 @@ mod linux_android {
         assert_eq!(None, lock_info(inode));
     }
 
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_rw_hint() {
+        use nix::errno::Errno;
+
+        let tmp = tempfile().unwrap();
+        match set_rw_hint(&tmp, RwHint::Short) {
+            Err(Errno::EINVAL) => {
+                skip!("Write hints are not supported. Skipping test.")
+            }
+            res => res.unwrap(),
+        }
+        assert_eq!(get_rw_hint(&tmp).unwrap(), RwHint::Short);
+
+        // Per-file hints were removed in Linux 5.17
+        match set_file_rw_hint(&tmp, RwHint::Long) {
+            Err(Errno::EINVAL) => (),
+            res => {
+                res.unwrap();
+                assert_eq!(get_file_rw_hint(&tmp).unwrap(), RwHint::Long);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", not(target_env = "musl")))]
+    #[cfg_attr(target_env = "uclibc", ignore)] // uclibc doesn't support OFD locks, but the test should still compile
+    fn test_ofd_lock_conflict_between_threads() {
+        use nix::errno::Errno;
+        use nix::fcntl::{ofd_lock, FlockArg};
+        use std::fs::File;
+        use std::thread;
+
+        let tmp = NamedTempFile::new().unwrap();
+        ofd_lock(&tmp, FlockArg::LockExclusive, 0, 512).unwrap();
+
+        // A separately opened file is a separate open file description, so
+        // its locks conflict even though it belongs to the same process.
+        let path = tmp.path().to_owned();
+        thread::spawn(move || {
+            let f = File::open(path).unwrap();
+            assert_eq!(
+                ofd_lock(&f, FlockArg::LockSharedNonblock, 256, 512),
+                Err(Errno::EAGAIN)
+            );
+            // Locks on disjoint ranges don't conflict.
+            ofd_lock(&f, FlockArg::LockSharedNonblock, 512, 512).unwrap();
+        })
+        .join()
+        .unwrap();
+
+        ofd_lock(&tmp, FlockArg::Unlock, 0, 512).unwrap();
+    }
+
     #[test]
     #[cfg(all(target_os = "linux", not(target_env = "musl")))]
     #[cfg_attr(target_env = "uclibc", ignore)] // uclibc doesn't support OFD locks, but the test should still compile
@@ -89,6 +89,47 @@ fn test_mq_timedreceive() {
     assert_eq!(msg_to_send, str::from_utf8(&buf[0..len]).unwrap());
 }
 
+#[test]
+fn test_mq_timed_timeout() {
+    use nix::mqueue::{mq_timedsend, mq_unlink};
+    const MSG_SIZE: mq_attr_member_t = 32;
+    let attr = MqAttr::new(0, 1, MSG_SIZE, 0);
+    let mq_name = "/a_nix_test_queue_timeout";
+
+    let oflag = MQ_OFlag::O_CREAT | MQ_OFlag::O_RDWR;
+    let mode = Mode::S_IWUSR | Mode::S_IRUSR | Mode::S_IRGRP | Mode::S_IROTH;
+    let r = mq_open(mq_name, oflag, mode, Some(&attr));
+    if let Err(Errno::ENOSYS) = r {
+        println!("message queues not supported or module not loaded?");
+        return;
+    };
+    let mqd = r.unwrap();
+    mq_unlink(mq_name).unwrap();
+
+    // Nothing to receive from the empty queue.
+    let mut buf = [0u8; 32];
+    let mut prio = 0u32;
+    let abstime = clock_gettime(ClockId::CLOCK_REALTIME).unwrap()
+        + TimeSpec::milliseconds(10);
+    assert_eq!(
+        mq_timedreceive(&mqd, &mut buf, &mut prio, &abstime),
+        Err(Errno::ETIMEDOUT)
+    );
+
+    // No room to send to the full queue.
+    let abstime =
+        clock_gettime(ClockId::CLOCK_REALTIME).unwrap() + TimeSpec::seconds(1);
+    mq_timedsend(&mqd, b"msg_1", 1, &abstime).unwrap();
+    let abstime = clock_gettime(ClockId::CLOCK_REALTIME).unwrap()
+        + TimeSpec::milliseconds(10);
+    assert_eq!(
+        mq_timedsend(&mqd, b"msg_2", 1, &abstime),
+        Err(Errno::ETIMEDOUT)
+    );
+
+    mq_close(mqd).unwrap();
+}
+
 #[test]
 fn test_mq_getattr() {
     use nix::mqueue::mq_getattr;
@@ -109,6 +150,32 @@ fn test_mq_getattr() {
     mq_close(mqd).unwrap();
 }
 
+#[test]
+fn test_mq_getattr_fields() {
+    use nix::mqueue::{mq_getattr, mq_unlink};
+    const MSG_SIZE: mq_attr_member_t = 64;
+    let initial_attr = MqAttr::new(0, 4, MSG_SIZE, 0);
+    let mq_name = "/attr_test_get_attr_fields";
+    let oflag = MQ_OFlag::O_CREAT | MQ_OFlag::O_WRONLY;
+    let mode = Mode::S_IWUSR | Mode::S_IRUSR | Mode::S_IRGRP | Mode::S_IROTH;
+    let r = mq_open(mq_name, oflag, mode, Some(&initial_attr));
+    if let Err(Errno::ENOSYS) = r {
+        println!("message queues not supported or module not loaded?");
+        return;
+    };
+    let mqd = r.unwrap();
+    mq_unlink(mq_name).unwrap();
+
+    let attr = mq_getattr(&mqd).unwrap();
+    assert_eq!(attr.msgsize(), MSG_SIZE);
+    assert_eq!(attr.maxmsg(), 4);
+    assert_eq!(attr.curmsgs(), 0);
+
+    mq_send(&mqd, b"msg_1", 1).unwrap();
+    assert_eq!(mq_getattr(&mqd).unwrap().curmsgs(), 1);
+    mq_close(mqd).unwrap();
+}
+
 // FIXME: Fix failures for mips in QEMU
 #[test]
 #[cfg_attr(
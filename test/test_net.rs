@@ -24,3 +24,58 @@ fn test_if_indextoname() {
         LOOPBACK
     );
 }
+
+#[test]
+fn test_getaddrinfo_localhost() {
+    use nix::net::addrinfo::{getaddrinfo, AddrInfoHints};
+    use nix::sys::socket::SockType;
+
+    let hints = AddrInfoHints::new().socktype(SockType::Stream);
+    let mut found = false;
+    for ai in getaddrinfo(Some("localhost"), Some("http"), Some(hints)).unwrap()
+    {
+        assert_eq!(ai.socktype, Some(SockType::Stream));
+        let address = ai.address.unwrap();
+        if let Some(sin) = address.as_sockaddr_in() {
+            assert!(sin.ip().is_loopback());
+            assert_eq!(sin.port(), 80);
+            found = true;
+        } else if let Some(sin6) = address.as_sockaddr_in6() {
+            assert!(sin6.ip().is_loopback());
+            assert_eq!(sin6.port(), 80);
+            found = true;
+        }
+    }
+    assert!(found);
+}
+
+#[test]
+fn test_getnameinfo_numeric() {
+    use nix::net::addrinfo::{getnameinfo, NameInfoFlags};
+    use nix::sys::socket::SockaddrIn;
+
+    let addr = SockaddrIn::new(127, 0, 0, 1, 8080);
+    let (host, service) = getnameinfo(
+        &addr,
+        NameInfoFlags::NI_NUMERICHOST | NameInfoFlags::NI_NUMERICSERV,
+    )
+    .unwrap();
+    assert_eq!(host, "127.0.0.1");
+    assert_eq!(service, "8080");
+}
+
+#[test]
+fn test_getaddrinfo_invalid_service() {
+    use nix::net::addrinfo::{getaddrinfo, AddrInfoHints};
+    use nix::sys::socket::SockType;
+
+    let hints = AddrInfoHints::new().socktype(SockType::Stream);
+    let err = getaddrinfo(
+        Some("localhost"),
+        Some("no-such-service-nix"),
+        Some(hints),
+    )
+    .unwrap_err();
+    assert!(!err.desc().is_empty());
+    assert!(!err.to_string().is_empty());
+}
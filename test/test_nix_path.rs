@@ -1 +1,67 @@
+use nix::sys::stat::Mode;
+use nix::unistd::mkdir;
+use nix::NixPath;
+use std::ffi::CString;
+use std::os::unix::ffi::OsStrExt;
 
+#[test]
+fn test_mkdir_cstring() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let dir = tempdir.path().join("dir");
+    let path = CString::new(dir.as_os_str().as_bytes()).unwrap();
+
+    // A CString is already NUL-terminated, so it is passed on without a copy.
+    let ptr = path.with_nix_path(|p| p.as_ptr()).unwrap();
+    assert_eq!(ptr, path.as_ptr());
+
+    mkdir(&path, Mode::S_IRWXU).unwrap();
+    assert!(dir.is_dir());
+}
+
+#[test]
+fn test_mkdir_vec() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let dir = tempdir.path().join("dir");
+    let path = dir.as_os_str().as_bytes().to_vec();
+
+    mkdir(&path, Mode::S_IRWXU).unwrap();
+    assert!(dir.is_dir());
+}
+
+#[test]
+fn test_nul_terminated_slice() {
+    let path = b"/tmp\0";
+    let ptr = path[..].with_nix_path(|p| {
+        assert_eq!(p.to_bytes(), b"/tmp");
+        p.as_ptr()
+    });
+    // Used in place, without a copy.
+    assert_eq!(ptr.unwrap(), path.as_ptr().cast());
+
+    // Interior NULs are still rejected.
+    assert_eq!(
+        b"/t\0mp\0"[..].with_nix_path(|_| ()),
+        Err(nix::errno::Errno::EINVAL)
+    );
+}
+
+#[test]
+fn test_large_nul_terminated_slice() {
+    // Longer than what would be copied to the stack.
+    let mut path = vec![b'a'; 64 * 1024];
+    path.push(0);
+    let len = path.with_nix_path(|p| p.to_bytes().len()).unwrap();
+    assert_eq!(len, 64 * 1024);
+}
+
+#[test]
+fn test_to_cstring() {
+    let path = std::ffi::OsStr::new("/tmp/nix");
+    let cstring = path.to_cstring().unwrap();
+    assert_eq!(cstring.as_bytes(), path.as_bytes());
+
+    assert_eq!(
+        std::ffi::OsStr::new("/t\0mp").to_cstring(),
+        Err(nix::errno::Errno::EINVAL)
+    );
+}
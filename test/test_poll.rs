@@ -63,6 +63,28 @@ fn test_ppoll() {
     assert!(fds[0].revents().unwrap().contains(PollFlags::POLLIN));
 }
 
+// The signal mask passed to ppoll(2) is only in effect during the call.
+#[cfg(any(linux_android, freebsdlike))]
+#[test]
+fn test_ppoll_sigmask_restored() {
+    use nix::poll::ppoll;
+    use nix::sys::signal::{SigSet, SigmaskHow, Signal};
+
+    let mut blocked = SigSet::empty();
+    blocked.add(Signal::SIGUSR1);
+    let old_mask = blocked.thread_swap_mask(SigmaskHow::SIG_BLOCK).unwrap();
+
+    let (r, w) = pipe().unwrap();
+    write(&w, b".").unwrap();
+    let mut fds = [PollFd::new(r.as_fd(), PollFlags::POLLIN)];
+    let nfds = ppoll(&mut fds, None, Some(SigSet::empty())).unwrap();
+    assert_eq!(nfds, 1);
+    assert!(fds[0].revents().unwrap().contains(PollFlags::POLLIN));
+
+    assert!(SigSet::thread_get_mask().unwrap().contains(Signal::SIGUSR1));
+    old_mask.thread_set_mask().unwrap();
+}
+
 #[test]
 fn test_pollfd_events() {
     let fd_zero = unsafe { BorrowedFd::borrow_raw(0) };
@@ -280,3 +280,70 @@ fn test_forkpty() {
         }
     }
 }
+
+#[test]
+#[cfg(linux_android)]
+fn test_tiocgpgrp() {
+    use nix::sys::signal::*;
+    use nix::sys::wait::wait;
+    use nix::unistd::{getpgrp, tcgetpgrp};
+
+    // forkpty calls openpty which uses ptname(3) internally.
+    let _m0 = crate::PTSNAME_MTX.lock();
+    // forkpty spawns a child process
+    let _m1 = crate::FORK_MTX.lock();
+
+    let res = unsafe { forkpty(None, None).unwrap() };
+    match res {
+        ForkptyResult::Child => {
+            // The pty is the child's controlling terminal.
+            let msg: &[u8] = match tiocspgrp(stdout(), getpgrp()) {
+                Ok(()) => b"y",
+                Err(_) => b"n",
+            };
+            write(stdout(), msg).unwrap();
+            pause();
+            unsafe {
+                _exit(0);
+            }
+        }
+        ForkptyResult::Parent { child, master } => {
+            let mut buf = [0u8; 1];
+            crate::read_exact(&master, &mut buf);
+            assert_eq!(&buf, b"y");
+
+            // The child leads its own session and foreground process group.
+            let pgid = tiocgpgrp(&master).unwrap();
+            assert_eq!(pgid, child);
+            assert_eq!(pgid, tcgetpgrp(&master).unwrap());
+
+            kill(child, SIGTERM).unwrap();
+            let status = wait().unwrap(); // keep other tests using generic wait from getting our child
+            assert_eq!(status, WaitStatus::Signaled(child, SIGTERM, false));
+        }
+    }
+}
+
+#[test]
+#[cfg(linux_android)]
+fn test_login_tty() {
+    use nix::sys::wait::waitpid;
+    use nix::unistd::{fork, getpgrp, tcgetpgrp, ForkResult};
+
+    // openpty uses ptname(3) internally.
+    let _m0 = crate::PTSNAME_MTX.lock();
+    let _m1 = crate::FORK_MTX.lock();
+
+    let pty = openpty(None, None).unwrap();
+    match unsafe { fork() }.unwrap() {
+        ForkResult::Child => {
+            let ok = login_tty(pty.slave).is_ok()
+                && tcgetpgrp(std::io::stdin()) == Ok(getpgrp());
+            unsafe { _exit(if ok { 0 } else { 1 }) };
+        }
+        ForkResult::Parent { child } => {
+            drop(pty.slave);
+            assert_eq!(waitpid(child, None), Ok(WaitStatus::Exited(child, 0)));
+        }
+    }
+}
@@ -37,3 +37,51 @@ fn test_sched_affinity() {
     // Finally, reset the initial CPU set
     sched_setaffinity(Pid::from_raw(0), &initial_affinity).unwrap();
 }
+
+#[test]
+fn test_cpuset_iter() {
+    let mut cpuset = CpuSet::new();
+    assert_eq!(cpuset.iter().next(), None);
+    assert_eq!(cpuset.count_set(), 0);
+
+    cpuset.set(0).unwrap();
+    cpuset.set(2).unwrap();
+    cpuset.set(CpuSet::count() - 1).unwrap();
+    assert_eq!(
+        cpuset.iter().collect::<Vec<_>>(),
+        [0, 2, CpuSet::count() - 1]
+    );
+    assert_eq!(cpuset.count_set(), 3);
+}
+
+#[test]
+fn test_sched_affinity_current_thread() {
+    let initial_affinity = sched_getaffinity(None).unwrap();
+    if !initial_affinity.is_set(0).unwrap() {
+        skip!("CPU 0 is not available to this thread. Skipping test.");
+    }
+
+    let mut cpu0 = CpuSet::new();
+    cpu0.set(0).unwrap();
+    sched_setaffinity(None, &cpu0).unwrap();
+    // `None` refers to the same thread as pid 0.
+    assert_eq!(sched_getaffinity(None).unwrap(), cpu0);
+    assert_eq!(sched_getaffinity(Pid::from_raw(0)).unwrap(), cpu0);
+
+    sched_setaffinity(None, &initial_affinity).unwrap();
+}
+
+#[test]
+fn test_cpuset_ops() {
+    let cpuset = |cpus: &[usize]| {
+        let mut set = CpuSet::new();
+        for &cpu in cpus {
+            set.set(cpu).unwrap();
+        }
+        set
+    };
+
+    assert_eq!(cpuset(&[0, 1, 2]) & cpuset(&[1, 2, 3]), cpuset(&[1, 2]));
+    assert_eq!(cpuset(&[0, 1]) | cpuset(&[2]), cpuset(&[0, 1, 2]));
+    assert_eq!(cpuset(&[0, 1, 2]) - cpuset(&[1, 3]), cpuset(&[0, 2]));
+}
@@ -1,4 +1,4 @@
-use nix::syslog::{openlog, syslog, Facility, LogFlags, Severity};
+use nix::syslog::{closelog, openlog, syslog, Facility, LogFlags, Severity};
 
 #[test]
 fn test_syslog_hello_world() {
@@ -36,3 +36,17 @@ fn test_openlog_with_ident() {
         .unwrap();
     syslog(Severity::LOG_EMERG, "Hello, ident!").unwrap();
 }
+
+#[test]
+fn test_closelog() {
+    #[cfg(not(target_os = "linux"))]
+    openlog(None::<&str>, LogFlags::LOG_NDELAY, Facility::LOG_USER).unwrap();
+    #[cfg(target_os = "linux")]
+    openlog(None, LogFlags::LOG_NDELAY, Facility::LOG_USER).unwrap();
+
+    syslog(Severity::LOG_DEBUG, "Hello, closelog!").unwrap();
+    closelog();
+    // Logging again reopens the connection on demand.
+    syslog(Severity::LOG_DEBUG, "Hello again, closelog!").unwrap();
+    closelog();
+}
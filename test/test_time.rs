@@ -8,6 +8,23 @@ pub fn test_clock_getres() {
     nix::time::clock_getres(ClockId::CLOCK_REALTIME).expect("assertion failed");
 }
 
+#[cfg(not(target_os = "redox"))]
+#[test]
+pub fn test_clock_getres_monotonic() {
+    use nix::sys::time::{TimeSpec, TimeValLike};
+
+    let res = nix::time::clock_getres(ClockId::CLOCK_MONOTONIC).unwrap();
+    assert!(res > TimeSpec::nanoseconds(0));
+    assert!(res < TimeSpec::milliseconds(1));
+}
+
+#[cfg(linux_android)]
+#[test]
+pub fn test_clock_tai() {
+    clock_gettime(ClockId::CLOCK_TAI).unwrap();
+    ClockId::CLOCK_TAI.res().unwrap();
+}
+
 #[test]
 pub fn test_clock_gettime() {
     clock_gettime(ClockId::CLOCK_REALTIME).expect("assertion failed");
@@ -64,3 +81,31 @@ pub fn test_clock_nanosleep() {
     let expected = TimeSpec::microseconds(0);
     assert_eq!(res, Ok(expected));
 }
+
+#[cfg(any(
+    linux_android,
+    solarish,
+    freebsdlike,
+    target_os = "netbsd",
+    target_os = "hurd",
+    target_os = "aix"
+))]
+#[test]
+pub fn test_clock_nanosleep_abstime() {
+    use nix::{
+        sys::time::{TimeSpec, TimeValLike},
+        time::{clock_nanosleep, ClockNanosleepFlags},
+    };
+
+    let start = clock_gettime(ClockId::CLOCK_MONOTONIC).unwrap();
+    let deadline = start + TimeSpec::milliseconds(20);
+    clock_nanosleep(
+        ClockId::CLOCK_MONOTONIC,
+        ClockNanosleepFlags::TIMER_ABSTIME,
+        &deadline,
+    )
+    .unwrap();
+    let now = clock_gettime(ClockId::CLOCK_MONOTONIC).unwrap();
+    assert!(now >= deadline);
+    assert!(now - start >= TimeSpec::milliseconds(20));
+}
@@ -684,6 +684,19 @@ fn test_pathconf_limited() {
     );
 }
 
+#[cfg_attr(target_os = "hurd", ignore)]
+#[test]
+fn test_fpathconf_pipe_buf() {
+    let (r, _w) = pipe().unwrap();
+    let pipe_buf = fpathconf(r, PathconfVar::PIPE_BUF);
+    assert!(
+        pipe_buf
+            .expect("fpathconf failed")
+            .expect("PIPE_BUF is unlimited")
+            > 0
+    );
+}
+
 #[cfg_attr(target_os = "hurd", ignore)]
 #[test]
 fn test_sysconf_limited() {
@@ -697,6 +710,17 @@ fn test_sysconf_limited() {
     );
 }
 
+#[test]
+fn test_sysconf_helpers() {
+    assert!(clk_tck() > 0);
+    assert!(open_max() > 2);
+    assert!(arg_max() >= 4096);
+    assert_eq!(
+        sysconf_or(SysconfVar::CLK_TCK, -1),
+        sysconf(SysconfVar::CLK_TCK).unwrap().unwrap()
+    );
+}
+
 #[cfg(target_os = "freebsd")]
 #[test]
 fn test_sysconf_unsupported() {
@@ -764,6 +788,69 @@ fn test_pipe2() {
     assert!(f1.contains(FdFlag::FD_CLOEXEC));
 }
 
+#[test]
+fn test_dup() {
+    use std::os::fd::AsRawFd;
+
+    let (r, w) = pipe().unwrap();
+    let dup_r = dup(&r).unwrap();
+    assert_ne!(dup_r.as_raw_fd(), r.as_raw_fd());
+    drop(r);
+
+    // The duplicate refers to the same pipe, and keeps it open.
+    write(&w, b"hello").unwrap();
+    let mut buf = [0u8; 5];
+    assert_eq!(read(&dup_r, &mut buf), Ok(5));
+    assert_eq!(&buf, b"hello");
+}
+
+#[test]
+fn test_dup_closes_on_drop() {
+    let (r, w) = pipe().unwrap();
+    let dup_w = dup(&w).unwrap();
+    drop(dup_w);
+
+    // Dropping the duplicate did not close the original.
+    write(&w, b"hello").unwrap();
+    drop(w);
+
+    // Once the original is dropped too, no write end is left open, so the
+    // duplicate was closed as well.
+    let mut buf = [0u8; 5];
+    assert_eq!(read(&r, &mut buf), Ok(5));
+    assert_eq!(read(&r, &mut buf), Ok(0));
+}
+
+#[cfg(any(
+    netbsdlike,
+    solarish,
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "hurd",
+    target_os = "linux"
+))]
+#[test]
+fn test_dup3() {
+    use nix::fcntl::{fcntl, FcntlArg, FdFlag};
+    use std::os::fd::AsRawFd;
+
+    let (r, w) = pipe().unwrap();
+    let (mut target, _w2) = pipe().unwrap();
+    let target_fd = target.as_raw_fd();
+
+    // dup3 replaces the open file behind `target`, keeping its number.
+    dup3(&r, &mut target, OFlag::O_CLOEXEC).unwrap();
+    assert_eq!(target.as_raw_fd(), target_fd);
+    let flags =
+        FdFlag::from_bits_truncate(fcntl(&target, FcntlArg::F_GETFD).unwrap());
+    assert!(flags.contains(FdFlag::FD_CLOEXEC));
+
+    write(&w, b"hello").unwrap();
+    let mut buf = [0u8; 5];
+    assert_eq!(read(&target, &mut buf), Ok(5));
+    assert_eq!(&buf, b"hello");
+}
+
 #[test]
 #[cfg(not(any(target_os = "redox", target_os = "fuchsia")))]
 fn test_truncate() {
@@ -1247,6 +1334,8 @@ fn test_setfsuid() {
 fn test_ttyname() {
     use std::os::fd::AsRawFd;
 
+    // ptsname(3) is not reentrant.
+    let _m = crate::PTSNAME_MTX.lock();
     let fd = posix_openpt(OFlag::O_RDWR).expect("posix_openpt failed");
     assert!(fd.as_raw_fd() > 0);
 
@@ -1262,17 +1351,28 @@ fn test_ttyname() {
         .open(Path::new(&sname))
         .expect("open failed");
 
-    let name = ttyname(fds).expect("ttyname failed");
+    assert_eq!(isatty(&fds), Ok(true));
+    let name = ttyname(&fds).expect("ttyname failed");
     assert!(name.starts_with("/dev"));
+    assert_eq!(name, Path::new(&sname));
+    #[cfg(linux_android)]
+    assert!(name.starts_with("/dev/pts/"), "{name:?}");
 }
 
 #[test]
 #[cfg(not(any(target_os = "redox", target_os = "fuchsia")))]
 fn test_ttyname_not_pty() {
     let fd = File::open("/dev/zero").unwrap();
+    assert_eq!(isatty(&fd), Ok(false));
     assert_eq!(ttyname(fd), Err(Errno::ENOTTY));
 }
 
+#[test]
+#[cfg(all(target_os = "linux", any(target_env = "gnu", target_env = "musl")))]
+fn test_ctermid() {
+    assert_eq!(ctermid(), Path::new("/dev/tty"));
+}
+
 #[test]
 #[cfg(bsd)]
 fn test_getpeereid() {